@@ -4,6 +4,8 @@ macro_rules! feature {
     };
 }
 
+use std::path::Path;
+
 #[cfg(target_feature = "crt-static")]
 compile_error!(r#"ERROR (raylib-bindings): Cannot statically link C runtime with executable!
 GLFW will fail to detect platform info if the C standard library is linked statically.
@@ -11,14 +13,189 @@ If you are on musl (or on another platform that links the C runtime statically b
 
 RUSTFLAGS='-C target-feature=-crt-static' cargo build"#);
 
-fn main() {
+#[cfg(any(
+    all(feature = "platform-glfw", feature = "platform-sdl"),
+    all(feature = "platform-glfw", feature = "platform-rgfw"),
+    all(feature = "platform-sdl", feature = "platform-rgfw")
+))]
+compile_error!(r#"ERROR (raylib-bindings): The `platform-glfw`, `platform-sdl` and `platform-rgfw` features are mutually exclusive!
+Enable at most one of them (GLFW is used when none is enabled)."#);
+
+#[cfg(any(
+    all(feature = "opengl11", any(feature = "opengl21", feature = "opengl33", feature = "opengl43", feature = "opengles2", feature = "opengles3")),
+    all(feature = "opengl21", any(feature = "opengl33", feature = "opengl43", feature = "opengles2", feature = "opengles3")),
+    all(feature = "opengl33", any(feature = "opengl43", feature = "opengles2", feature = "opengles3")),
+    all(feature = "opengl43", any(feature = "opengles2", feature = "opengles3")),
+    all(feature = "opengles2", feature = "opengles3")
+))]
+compile_error!(r#"ERROR (raylib-bindings): The `opengl11`, `opengl21`, `opengl33`, `opengl43`, `opengles2` and `opengles3` features are mutually exclusive!
+Enable at most one of them (raylib chooses the version for the platform when none is enabled)."#);
+
+/// Value of raylib's `OPENGL_VERSION` cmake option
+fn opengl_version() -> &'static str {
+    if cfg!(feature = "opengl11") { "1.1" }
+    else if cfg!(feature = "opengl21") { "2.1" }
+    else if cfg!(feature = "opengl33") { "3.3" }
+    else if cfg!(feature = "opengl43") { "4.3" }
+    else if cfg!(feature = "opengles2") { "ES 2.0" }
+    else if cfg!(feature = "opengles3") { "ES 3.0" }
+    else { "OFF" }
+}
+
+/// Value of raylib's `PLATFORM` cmake option
+/// (`RGFW` is added to raylib's cmake files by `patches/0002-rgfw-cmake-platform.patch`)
+fn platform() -> &'static str {
+    if cfg!(feature = "platform-sdl") { "SDL" }
+    else if cfg!(feature = "platform-rgfw") { "RGFW" }
+    else { "Desktop" }
+}
+
+/// Libraries needed by the platform backend, which aren't part of the static library
+fn link_platform() {
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    if cfg!(feature = "platform-sdl") {
+        #[cfg(feature = "platform-sdl")]
+        if pkg_config::probe_library("sdl2").is_ok() { return }
+
+        // no pkg-config (usually on windows), hope SDL2 is in the library path
+        println!("cargo:rustc-link-lib=SDL2");
+    } else if cfg!(feature = "platform-rgfw") {
+        match target_os.as_str() {
+            "windows" => for lib in ["gdi32", "opengl32", "shell32", "winmm"] {
+                println!("cargo:rustc-link-lib={lib}");
+            },
+            "macos" => for framework in ["Cocoa", "CoreVideo", "IOKit", "OpenGL"] {
+                println!("cargo:rustc-link-lib=framework={framework}");
+            },
+            _ => for lib in ["X11", "Xrandr", "GL"] {
+                println!("cargo:rustc-link-lib={lib}");
+            }
+        }
+    }
+}
+
+/// raylib version the bindings were generated for, from the constants `src/ffi.rs` was generated with (from `raylib_api.json`)
+fn expected_version() -> (u32, u32) {
+    let ffi = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/ffi.rs")).expect("src/ffi.rs to exist");
+    let constant = |name: &str| ffi.lines()
+        .find_map(|line| line.strip_prefix(&format!("pub const {name}: i32 = "))?.strip_suffix(';')?.parse().ok())
+        .unwrap_or_else(|| panic!("src/ffi.rs to define {name}"));
+
+    (constant("RAYLIB_VERSION_MAJOR"), constant("RAYLIB_VERSION_MINOR"))
+}
+
+/// Version of an installed raylib, read from its header
+fn header_version(include_dir: &Path) -> Option<(u32, u32)> {
+    let header = std::fs::read_to_string(include_dir.join("raylib.h")).ok()?;
+    let define = |name: &str| header.lines()
+        .find_map(|line| line.strip_prefix("#define ")?.strip_prefix(name)?.trim().parse().ok());
+
+    Some((define("RAYLIB_VERSION_MAJOR")?, define("RAYLIB_VERSION_MINOR")?))
+}
+
+fn check_version(found: Option<(u32, u32)>, location: &str) {
+    let (major, minor) = expected_version();
+    match found {
+        Some(found) if found == (major, minor) => {}
+        Some((found_major, found_minor)) => panic!(r#"ERROR (raylib-bindings): Found raylib {found_major}.{found_minor} in {location}, but the bindings were generated for raylib {major}.{minor}!
+Install raylib {major}.{minor}, or build the vendored copy by unsetting `RAYLIB_SYS_DIR` and disabling the `system` feature."#),
+        None => panic!(r#"ERROR (raylib-bindings): Could not read the version of the raylib found in {location}!
+The bindings were generated for raylib {major}.{minor}, check that `raylib.h` is installed next to the library."#)
+    }
+}
+
+/// Stops the build if features that change how raylib is compiled are set, since an installed raylib can't be rebuilt
+fn check_installed_build_options(location: &str) {
+    let options = [
+        ("custom_frame_control", cfg!(feature = "custom_frame_control")),
+        ("null_audio", cfg!(feature = "null_audio")),
+        ("compression (disabled)", !cfg!(feature = "compression"))
+    ];
+    let set: Vec<_> = options.iter().filter(|(_, set)| *set).map(|(name, _)| format!("`{name}`")).collect();
+
+    if !set.is_empty() {
+        panic!(r#"ERROR (raylib-bindings): The {} feature(s) change how raylib is compiled, and can't be used with the raylib from {location}!
+Use the default for these features, or build the vendored copy by unsetting `RAYLIB_SYS_DIR` and disabling the `system` feature."#, set.join(", "));
+    }
+}
+
+/// Libraries an installed raylib depends on, in case it is a static library (like raylib's `Libs.private`)
+fn link_system_libs() {
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    if !cfg!(any(feature = "platform-sdl", feature = "platform-rgfw")) {
+        // glfw is compiled into raylib
+        match target_os.as_str() {
+            "windows" => for lib in ["gdi32", "opengl32", "shell32", "user32", "winmm"] {
+                println!("cargo:rustc-link-lib={lib}");
+            },
+            "macos" => for framework in ["Cocoa", "CoreVideo", "IOKit", "OpenGL"] {
+                println!("cargo:rustc-link-lib=framework={framework}");
+            },
+            _ => {}
+        }
+    }
+    if !matches!(target_os.as_str(), "windows" | "macos") {
+        for lib in ["m", "pthread", "dl"] {
+            println!("cargo:rustc-link-lib={lib}");
+        }
+    }
+    link_platform();
+}
+
+/// Links the raylib installed in `RAYLIB_SYS_DIR` (with `lib/` and `include/` subdirectories)
+fn link_sys_dir(dir: &Path) {
+    let location = dir.display().to_string();
+    check_version(header_version(&dir.join("include")), &location);
+    check_installed_build_options(&location);
+
+    println!("cargo:rustc-link-search=native={}", dir.join("lib").display());
+    println!("cargo:rustc-link-lib=raylib");
+    link_system_libs();
+}
+
+/// Links the raylib found by pkg-config
+#[cfg(feature = "system")]
+fn link_pkg_config() {
+    let library = pkg_config::Config::new().probe("raylib").unwrap_or_else(|e| panic!(r#"ERROR (raylib-bindings): Could not find raylib with pkg-config!
+Install raylib (with its `raylib.pc` file), set `RAYLIB_SYS_DIR` to where it is installed, or disable the `system` feature to build the vendored copy.
+
+{e}"#));
+
+    // the version in `raylib.pc` comes from cmake and can lag behind the header's, which is the one the api is generated from
+    let version = library.include_paths.iter().find_map(|dir| header_version(dir)).or_else(|| {
+        let mut numbers = library.version.split('.').map(|n| n.parse().ok());
+        Some((numbers.next()??, numbers.next()??))
+    });
+    check_version(version, "pkg-config");
+    check_installed_build_options("pkg-config");
+}
+
+/// Compiles the vendored raylib with cmake
+fn build_vendored() {
     let mut config = cmake::Config::new("raylib");
 
+    if cfg!(feature = "null_audio") {
+        // only compile miniaudio's null backend, which doesn't need a sound card
+        config.cflag("-DMA_ENABLE_ONLY_SPECIFIC_BACKENDS -DMA_ENABLE_NULL");
+    }
+
     let path = config
         .define("BUILD_EXAMPLES", "OFF")
+        .define("PLATFORM", platform())
+        .define("OPENGL_VERSION", opengl_version())
         .define("CUSTOMIZE_BUILD", "ON")
+        .define("SUPPORT_MODULE_RSHAPES", feature!("shapes"))
+        .define("SUPPORT_MODULE_RTEXTURES", feature!("textures"))
+        .define("SUPPORT_MODULE_RTEXT", feature!("text"))
+        .define("SUPPORT_MODULE_RMODELS", feature!("models"))
+        .define("SUPPORT_MODULE_RAUDIO", feature!("audio"))
+        .define("USE_AUDIO", feature!("audio"))
         .define("SUPPORT_FILEFORMAT_FNT", "OFF") // builtin text
         .define("SUPPORT_FILEFORMAT_TTF", "OFF") // builtin text
+        .define("SUPPORT_COMPRESSION_API", feature!("compression"))
+        .define("SUPPORT_CUSTOM_FRAME_CONTROL", feature!("custom_frame_control"))
         .define("SUPPORT_IMAGE_EXPORT", feature!("image_export"))
         .define("SUPPORT_IMAGE_GENERATION", feature!("image_generation"))
         .define("SUPPORT_IMAGE_MANIPULATION", feature!("image_manipulation"))
@@ -55,7 +232,26 @@ fn main() {
 
     println!("cargo:rustc-link-search=native={}/lib", path.display());
     println!("cargo:rustc-link-lib=static=raylib");
+    link_platform();
+
+    // the platform library's symbols (glfw, SDL) are only reachable when raylib is built here
+    println!("cargo:rustc-cfg=raylib_vendored");
+}
+
+fn main() {
+    // the build options set by features only apply to the vendored copy, an installed raylib is used as is
+    if let Some(dir) = std::env::var_os("RAYLIB_SYS_DIR") {
+        link_sys_dir(Path::new(&dir));
+    } else if cfg!(feature = "system") {
+        #[cfg(feature = "system")]
+        link_pkg_config();
+    } else {
+        build_vendored();
+    }
 
+    println!("cargo:rustc-check-cfg=cfg(raylib_vendored)");
+    println!("cargo:rerun-if-env-changed=RAYLIB_SYS_DIR");
+    println!("cargo:rerun-if-changed=src/ffi.rs");
     println!("cargo:rerun-if-changed=build/main.rs");
     println!("cargo:rerun-if-changed=build/generate.rs");
     println!("cargo:rerun-if-changed=build/parser.rs");
@@ -27,6 +27,11 @@ git remote add origin https://github.com/raysan5/raylib.git
 git fetch origin --depth=1 39f12859dcdf92822c8fefece1135bf6e76a1573
 git reset --hard 39f12859dcdf92822c8fefece1135bf6e76a1573
 
+# Changes the bindings need that aren't upstream
+for patch in ../patches/*.patch; do
+    git apply "$patch"
+done
+
 # Remove history (~350 MB in a full clone)
 # Maybe it's possible to remove all history up until the commit hash,
 # but I haven't found a nice way that allowed me to do it "automatically" like submodules
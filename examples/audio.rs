@@ -0,0 +1,24 @@
+use raylib::prelude::*;
+
+fn main() {
+    let rl = &mut init_window(800, 450, "Audio", 60);
+    let audio = init_audio_device(rl).unwrap();
+
+    let coin = Sound::load(&audio, "assets/coin.wav").unwrap();
+    let mut pitch = 1.0;
+
+    while !window_should_close(rl) {
+        if is_key_pressed(rl, Key::Up) { pitch += 0.1 }
+        if is_key_pressed(rl, Key::Down) { pitch -= 0.1 }
+        if is_key_pressed(rl, Key::Space) {
+            coin.set_pitch(pitch);
+            coin.play();
+        }
+
+        begin_drawing(rl, |rl| {
+            clear_background(rl, Color::RAYWHITE);
+            draw_text(rl, rl.default_font(), "Press SPACE to play the sound", vec2(20.0, 20.0), 20.0, Color::BLACK);
+            draw_text(rl, rl.default_font(), &format!("Pitch (UP/DOWN): {pitch:.1}"), vec2(20.0, 50.0), 20.0, Color::DARKGRAY);
+        });
+    }
+}
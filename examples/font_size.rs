@@ -3,7 +3,7 @@ use raylib::prelude::*;
 fn main() {
     let rl = &mut init_window(800, 800, "Font size", 60);
 
-    let font = load_font_bytes(rl, include_bytes!("../assets/iosevka-medium.ttc").as_slice());
+    let font = load_font_bytes(rl, include_bytes!("../assets/iosevka-medium.ttc").as_slice()).unwrap();
 
     while !window_should_close(rl) {
         begin_drawing(rl, |rl| {
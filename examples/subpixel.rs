@@ -3,7 +3,7 @@ use raylib::prelude::*;
 fn main() {
     let rl = &mut init_window(400, 400, "Subpixel positioning", 60);
 
-    let font = load_font_bytes(rl, include_bytes!("../assets/iosevka-medium.ttc").as_slice());
+    let font = load_font_bytes(rl, include_bytes!("../assets/iosevka-medium.ttc").as_slice()).unwrap();
 
     let mut pos = 0.0;
 
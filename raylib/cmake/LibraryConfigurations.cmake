@@ -96,6 +96,24 @@ elseif ("${PLATFORM}" MATCHES "SDL")
     set(PLATFORM_CPP "PLATFORM_DESKTOP_SDL")
     set(LIBS_PRIVATE SDL2::SDL2)
 
+elseif ("${PLATFORM}" MATCHES "RGFW")
+    set(PLATFORM_CPP "PLATFORM_DESKTOP_RGFW")
+
+    if (APPLE)
+        find_library(COCOA_LIBRARY Cocoa)
+        find_library(COREVIDEO_LIBRARY CoreVideo)
+        find_library(IOKIT_LIBRARY IOKit)
+        find_library(OPENGL_LIBRARY OpenGL)
+        set(LIBS_PRIVATE ${COCOA_LIBRARY} ${COREVIDEO_LIBRARY} ${IOKIT_LIBRARY} ${OPENGL_LIBRARY})
+        add_definitions(-DGL_SILENCE_DEPRECATION)
+    elseif (WIN32)
+        add_definitions(-D_CRT_SECURE_NO_WARNINGS)
+        set(LIBS_PRIVATE gdi32 opengl32 shell32 winmm)
+    else ()
+        find_library(pthread NAMES pthread)
+        set(LIBS_PRIVATE X11 Xrandr GL m pthread dl)
+    endif ()
+
 endif ()
 
 if (NOT ${OPENGL_VERSION} MATCHES "OFF")
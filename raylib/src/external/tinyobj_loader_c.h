@@ -494,6 +494,34 @@ static char *my_strndup(const char *s, unsigned int len) {
   return d;
 }
 
+#if defined(TINYOBJ_LOAD_FILE_TEXT)
+/* Reads a line from a text buffer (like dynamic_fgets), advancing the cursor */
+static char *text_fgets(char **buf, unsigned int *size, const char **cursor) {
+  const char *start = *cursor;
+  const char *end;
+  unsigned int length;
+
+  if (*start == '\0') {
+    return NULL;
+  }
+
+  end = strchr(start, '\n');
+  end = end ? end + 1 : start + strlen(start);
+  length = (unsigned int)(end - start);
+
+  if (length + 1 > *size) {
+    *size = length + 1;
+    *buf = (char*)TINYOBJ_REALLOC(*buf, *size);
+  }
+
+  memcpy(*buf, start, length);
+  (*buf)[length] = '\0';
+  *cursor = end;
+
+  return *buf;
+}
+#endif
+
 char *dynamic_fgets(char **buf, unsigned int *size, FILE *file) {
   char *offset;
   char *ret;
@@ -729,7 +757,12 @@ static int tinyobj_parse_and_index_mtl_file(tinyobj_material_t **materials_out,
   tinyobj_material_t material;
   unsigned int buffer_size = 128;
   char *linebuf;
+#if defined(TINYOBJ_LOAD_FILE_TEXT)
+  char *text;
+  const char *cursor;
+#else
   FILE *fp;
+#endif
   unsigned int num_materials = 0;
   tinyobj_material_t *materials = NULL;
   int has_previous_material = 0;
@@ -746,17 +779,30 @@ static int tinyobj_parse_and_index_mtl_file(tinyobj_material_t **materials_out,
   (*materials_out) = NULL;
   (*num_materials_out) = 0;
 
+#if defined(TINYOBJ_LOAD_FILE_TEXT)
+  /* Read the whole file through the user provided loader */
+  text = TINYOBJ_LOAD_FILE_TEXT(filename);
+  if (!text) {
+    return TINYOBJ_ERROR_FILE_OPERATION;
+  }
+  cursor = text;
+#else
   fp = fopen(filename, "rt");
   if (!fp) {
     fprintf(stderr, "TINYOBJ: Error reading file '%s': %s (%d)\n", filename, strerror(errno), errno);
     return TINYOBJ_ERROR_FILE_OPERATION;
   }
+#endif
 
   /* Create a default material */
   initMaterial(&material);
 
   linebuf = (char*)TINYOBJ_MALLOC(buffer_size);
+#if defined(TINYOBJ_LOAD_FILE_TEXT)
+  while (NULL != text_fgets(&linebuf, &buffer_size, &cursor)) {
+#else
   while (NULL != dynamic_fgets(&linebuf, &buffer_size, fp)) {
+#endif
     const char *token = linebuf;
 
     line_end = token + strlen(token);
@@ -948,7 +994,11 @@ static int tinyobj_parse_and_index_mtl_file(tinyobj_material_t **materials_out,
     /* @todo { unknown parameter } */
   }
 
+#if defined(TINYOBJ_LOAD_FILE_TEXT)
+  TINYOBJ_UNLOAD_FILE_TEXT(text);
+#else
   fclose(fp);
+#endif
 
   if (material.name) {
     /* Flush last material element */
@@ -63,6 +63,8 @@
     #define TINYOBJ_CALLOC RL_CALLOC
     #define TINYOBJ_REALLOC RL_REALLOC
     #define TINYOBJ_FREE RL_FREE
+    #define TINYOBJ_LOAD_FILE_TEXT LoadFileText     // Read .mtl files through raylib, so custom file callbacks are used
+    #define TINYOBJ_UNLOAD_FILE_TEXT UnloadFileText
 
     #define TINYOBJ_LOADER_C_IMPLEMENTATION
     #include "external/tinyobj_loader_c.h"      // OBJ/MTL file formats loading
@@ -0,0 +1,119 @@
+//! Registry of Rust closures called from raylib's audio thread.
+//!
+//! Raylib audio callbacks (`AudioCallback`) are bare function pointers without any user data,
+//! so a closure can't be passed to them directly.
+//! Instead, a fixed set of `extern "C"` trampolines is generated, each bound to one slot of the registry.
+//! Registering a closure reserves a free slot and returns the trampoline that calls it.
+//!
+//! Every slot is keyed by what it is registered on (see [`Owner`]),
+//! so that a stream's callback can be found back from the stream's buffer.
+//!
+//! NOTE: raylib calls audio callbacks while holding its internal mixer lock,
+//! and takes the same lock when setting, attaching or detaching them.
+//! Slots must only be released after the trampoline has been removed from raylib,
+//! and raylib functions must never be called while a slot is locked (or the audio thread could deadlock).
+
+use std::{ffi::c_void, panic::{catch_unwind, AssertUnwindSafe}, sync::Mutex};
+
+use crate::ffi;
+
+use super::AudioError;
+
+/// A callback receiving the raw buffer pointer and the number of frames in it.
+pub(crate) type RawCallback = Box<dyn FnMut(*mut c_void, u32) + Send>;
+
+/// What a slot is registered on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Owner {
+    /// Callback generating the data of a stream, keyed by the stream's `rAudioBuffer` pointer.
+    StreamCallback(usize),
+    /// Processor attached to a stream, keyed by the stream's `rAudioBuffer` pointer.
+    StreamProcessor(usize),
+    /// Processor attached to the mixed output of the device.
+    MixedProcessor
+}
+
+struct Slot {
+    /// `None` if the slot is free.
+    owner: Option<Owner>,
+    callback: Option<RawCallback>
+}
+
+/// Maximum number of callbacks and processors that can be registered at the same time.
+pub const MAX_AUDIO_CALLBACKS: usize = 32;
+
+macro_rules! registry {
+    ($($index:literal)*) => {
+        static SLOTS: [Mutex<Slot>; MAX_AUDIO_CALLBACKS] = [$({ let _ = $index; Mutex::new(Slot { owner: None, callback: None }) }),*];
+
+        const TRAMPOLINES: [ffi::AudioCallback; MAX_AUDIO_CALLBACKS] = [$({
+            extern "C" fn trampoline(data: *mut c_void, frames: u32) {
+                call($index, data, frames)
+            }
+            trampoline
+        }),*];
+    };
+}
+
+registry!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+
+fn call(index: usize, data: *mut c_void, frames: u32) {
+    let mut slot = SLOTS[index].lock().unwrap_or_else(|e| e.into_inner());
+    let Some(callback) = &mut slot.callback else { return };
+
+    // unwinding into raylib would abort the process: a callback that panics is removed instead,
+    // and the buffer is left as it is
+    if catch_unwind(AssertUnwindSafe(|| callback(data, frames))).is_err() {
+        let _panicked = slot.callback.take();
+        drop(slot);
+    }
+}
+
+/// A reserved slot in the callback registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SlotId(usize);
+
+impl SlotId {
+    /// The trampoline calling the closure stored in this slot.
+    pub(crate) fn trampoline(self) -> ffi::AudioCallback {
+        TRAMPOLINES[self.0]
+    }
+}
+
+/// Reserves a free slot for the given owner and stores the callback in it.
+/// Returns [`AudioError::TooManyCallbacks`] if all [`MAX_AUDIO_CALLBACKS`] slots are taken.
+pub(crate) fn register(owner: Owner, callback: RawCallback) -> Result<SlotId, AudioError> {
+    for (index, slot) in SLOTS.iter().enumerate() {
+        let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+        if slot.owner.is_none() {
+            slot.owner = Some(owner);
+            slot.callback = Some(callback);
+            return Ok(SlotId(index))
+        }
+    }
+    Err(AudioError::TooManyCallbacks)
+}
+
+/// Finds the first slot registered with the given owner.
+pub(crate) fn find(owner: Owner) -> Option<SlotId> {
+    SLOTS.iter().position(|slot| slot.lock().unwrap_or_else(|e| e.into_inner()).owner == Some(owner)).map(SlotId)
+}
+
+/// Replaces the callback stored in an already reserved slot.
+pub(crate) fn replace(id: SlotId, callback: RawCallback) {
+    // drop the previous callback outside of the lock
+    let _previous = {
+        let mut slot = SLOTS[id.0].lock().unwrap_or_else(|e| e.into_inner());
+        slot.callback.replace(callback)
+    };
+}
+
+/// Releases a slot, dropping its callback.
+/// The trampoline must not be reachable from raylib anymore.
+pub(crate) fn release(id: SlotId) {
+    let _previous = {
+        let mut slot = SLOTS[id.0].lock().unwrap_or_else(|e| e.into_inner());
+        slot.owner = None;
+        slot.callback.take()
+    };
+}
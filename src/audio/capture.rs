@@ -0,0 +1,76 @@
+use super::processor::AudioProcessor;
+
+/// Records the samples going through it, without changing them.
+///
+/// Attached to the device with [`AudioDevice::attach_mixed_processor`](super::AudioDevice::attach_mixed_processor),
+/// it captures everything that is played.
+/// Combined with the `null_audio` feature, this allows testing audio code on machines without a sound card.
+///
+/// NOTE: the capture buffer grows from the audio thread, so set a limit with [`Capture::with_limit`] for long captures.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// // no window needed
+/// let audio = AudioDevice::init().unwrap();
+/// let capture = audio.attach_mixed_processor(Capture::new()).unwrap();
+/// let sound = Sound::load(&audio, "assets/coin.wav").unwrap();
+/// sound.play();
+/// while sound.is_playing() {
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+/// }
+/// let samples = capture.lock().take();
+/// assert!(samples.iter().any(|sample| sample.abs() > 0.1));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Capture {
+    samples: Vec<f32>,
+    channels: usize,
+    limit: Option<usize>
+}
+
+impl Capture {
+    /// Creates a capture keeping every sample.
+    pub fn new() -> Self {
+        Capture::default()
+    }
+    /// Creates a capture keeping only the most recent `max_frames` frames.
+    pub fn with_limit(max_frames: usize) -> Self {
+        Capture { limit: Some(max_frames), ..Default::default() }
+    }
+
+    /// Interleaved samples captured so far.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+    /// Number of channels of the captured samples (0 if nothing was captured yet).
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+    /// Number of frames captured so far.
+    pub fn frame_count(&self) -> usize {
+        self.samples.len().checked_div(self.channels).unwrap_or(0)
+    }
+
+    /// Takes the samples captured so far, leaving the capture empty.
+    pub fn take(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.samples)
+    }
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
+impl AudioProcessor for Capture {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        if self.channels != channels {
+            self.samples.clear();
+            self.channels = channels;
+        }
+        self.samples.extend_from_slice(samples);
+
+        if let Some(limit) = self.limit {
+            let excess = self.samples.len().saturating_sub(limit * channels);
+            self.samples.drain(..excess);
+        }
+    }
+}
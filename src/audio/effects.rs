@@ -0,0 +1,489 @@
+//! Built-in [`AudioProcessor`]s.
+//!
+//! Effects that depend on time (filters, delays, ...) need to know the sample rate they run at.
+//! When attaching them to the device, use [`AudioDevice::sample_rate`](super::AudioDevice::sample_rate).
+
+use std::f32::consts::TAU;
+
+use super::processor::AudioProcessor;
+
+/// Multiplies every sample by a constant factor.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut gain = Gain::from_db(-6.0);
+/// let mut samples = [1.0, -1.0];
+/// gain.process(&mut samples, 2);
+/// assert!((samples[0] - 0.5).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gain {
+    gain: f32
+}
+
+impl Gain {
+    /// Creates a gain with a linear factor (1.0 keeps the signal unchanged).
+    pub fn new(gain: f32) -> Self {
+        Gain { gain }
+    }
+    /// Creates a gain with a factor in decibels (0.0 keeps the signal unchanged).
+    pub fn from_db(db: f32) -> Self {
+        Gain::new(db_to_linear(db))
+    }
+
+    /// Linear gain factor.
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+    /// Sets the linear gain factor.
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain;
+    }
+}
+
+impl AudioProcessor for Gain {
+    fn process(&mut self, samples: &mut [f32], _channels: usize) {
+        samples.iter_mut().for_each(|sample| *sample *= self.gain);
+    }
+}
+
+/// Response of a [`Biquad`] filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterKind {
+    /// Keeps frequencies below the cutoff (muffled, underwater sound).
+    LowPass,
+    /// Keeps frequencies above the cutoff (thin, radio-like sound).
+    HighPass
+}
+
+/// A second order low-pass or high-pass filter.
+///
+/// Coefficients follow the "Audio EQ Cookbook" formulas.
+/// # Example
+/// A low-pass filter lets a constant signal through, but cuts off a signal alternating at the Nyquist frequency:
+/// ```
+/// # use raylib::prelude::*;
+/// let mut filter = Biquad::low_pass(44100.0, 500.0, 0.707);
+/// let mut constant = vec![1.0; 4096];
+/// filter.process(&mut constant, 1);
+/// assert!((constant[4095] - 1.0).abs() < 0.01);
+///
+/// filter.reset();
+/// let mut alternating: Vec<f32> = (0..4096).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+/// filter.process(&mut alternating, 1);
+/// assert!(alternating[4095].abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Biquad {
+    kind: FilterKind,
+    sample_rate: f32,
+    cutoff: f32,
+    q: f32,
+    /// Normalized coefficients `[b0, b1, b2, a1, a2]`.
+    coefficients: [f32; 5],
+    /// Transposed direct form II state, per channel.
+    state: Vec<[f32; 2]>
+}
+
+impl Biquad {
+    /// Creates a filter.
+    /// `cutoff` is in Hz, and `q` sets the resonance around the cutoff (0.707 gives a flat response).
+    ///
+    /// # Panics
+    /// Panics if `sample_rate` or `q` are not positive, or if `cutoff` is not between 0 and half of `sample_rate`.
+    pub fn new(kind: FilterKind, sample_rate: f32, cutoff: f32, q: f32) -> Self {
+        let mut filter = Biquad { kind, sample_rate, cutoff, q, coefficients: [0.0; 5], state: Vec::new() };
+        filter.update_coefficients();
+        filter
+    }
+    /// Creates a low-pass filter.
+    pub fn low_pass(sample_rate: f32, cutoff: f32, q: f32) -> Self {
+        Biquad::new(FilterKind::LowPass, sample_rate, cutoff, q)
+    }
+    /// Creates a high-pass filter.
+    pub fn high_pass(sample_rate: f32, cutoff: f32, q: f32) -> Self {
+        Biquad::new(FilterKind::HighPass, sample_rate, cutoff, q)
+    }
+
+    pub fn kind(&self) -> FilterKind {
+        self.kind
+    }
+    /// Cutoff frequency in Hz.
+    pub fn cutoff(&self) -> f32 {
+        self.cutoff
+    }
+    pub fn q(&self) -> f32 {
+        self.q
+    }
+
+    /// Changes the response of the filter, keeping its state to avoid clicks.
+    pub fn set_kind(&mut self, kind: FilterKind) {
+        self.kind = kind;
+        self.update_coefficients();
+    }
+    /// Changes the cutoff frequency, keeping the filter's state to avoid clicks.
+    /// # Panics
+    /// Panics if `cutoff` is not between 0 and half of the sample rate.
+    pub fn set_cutoff(&mut self, cutoff: f32) {
+        self.cutoff = cutoff;
+        self.update_coefficients();
+    }
+    /// Changes the resonance, keeping the filter's state to avoid clicks.
+    /// # Panics
+    /// Panics if `q` is not positive.
+    pub fn set_q(&mut self, q: f32) {
+        self.q = q;
+        self.update_coefficients();
+    }
+
+    /// Clears the filter's memory of previous samples.
+    pub fn reset(&mut self) {
+        self.state.iter_mut().for_each(|state| *state = [0.0; 2]);
+    }
+
+    fn update_coefficients(&mut self) {
+        assert!(self.sample_rate > 0.0, "the sample rate must be positive");
+        assert!(self.cutoff > 0.0 && self.cutoff < self.sample_rate / 2.0, "the cutoff must be between 0 and the nyquist frequency");
+        assert!(self.q > 0.0, "q must be positive");
+
+        let w0 = TAU * self.cutoff / self.sample_rate;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * self.q);
+
+        let (b0, b1, b2) = match self.kind {
+            FilterKind::LowPass => ((1.0 - cos) / 2.0, 1.0 - cos, (1.0 - cos) / 2.0),
+            FilterKind::HighPass => ((1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0)
+        };
+        let (a0, a1, a2) = (1.0 + alpha, -2.0 * cos, 1.0 - alpha);
+
+        self.coefficients = [b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0];
+    }
+}
+
+impl AudioProcessor for Biquad {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        if self.state.len() != channels { self.state.resize(channels, [0.0; 2]) }
+        let [b0, b1, b2, a1, a2] = self.coefficients;
+
+        for frame in samples.chunks_exact_mut(channels) {
+            for (sample, [z1, z2]) in frame.iter_mut().zip(&mut self.state) {
+                let x = *sample;
+                let y = b0 * x + *z1;
+                *z1 = b1 * x - a1 * y + *z2;
+                *z2 = b2 * x - a2 * y;
+                *sample = y;
+            }
+        }
+    }
+}
+
+/// Repeats the signal after a delay.
+/// With some feedback, the repetitions get repeated themselves, creating an echo.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// // 2 frames of delay at 4 Hz, no feedback, only the delayed signal
+/// let mut delay = Delay::new(4.0, 0.5, 0.0, 1.0);
+/// let mut samples = [1.0, 2.0, 3.0, 4.0];
+/// delay.process(&mut samples, 1);
+/// assert_eq!(samples, [0.0, 0.0, 1.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delay {
+    sample_rate: f32,
+    delay: f32,
+    feedback: f32,
+    mix: f32,
+    /// Interleaved ring buffer of delayed frames.
+    buffer: Vec<f32>,
+    /// Current frame in the ring buffer.
+    position: usize
+}
+
+impl Delay {
+    /// Creates a delay.
+    /// - `delay` is the time between repetitions, in seconds.
+    /// - `feedback` is the volume of each repetition relative to the previous one (0.0 for a single repetition).
+    /// - `mix` is the balance between the original signal (0.0) and the delayed signal (1.0).
+    ///
+    /// # Panics
+    /// Panics if `sample_rate` or `delay` are not positive.
+    pub fn new(sample_rate: f32, delay: f32, feedback: f32, mix: f32) -> Self {
+        assert!(sample_rate > 0.0, "the sample rate must be positive");
+        assert!(delay > 0.0, "the delay must be positive");
+        Delay { sample_rate, delay, feedback, mix, buffer: Vec::new(), position: 0 }
+    }
+
+    /// Delay in seconds.
+    pub fn delay(&self) -> f32 {
+        self.delay
+    }
+    pub fn feedback(&self) -> f32 {
+        self.feedback
+    }
+    pub fn mix(&self) -> f32 {
+        self.mix
+    }
+
+    /// Changes the delay, which clears the delayed signal.
+    /// # Panics
+    /// Panics if `delay` is not positive.
+    pub fn set_delay(&mut self, delay: f32) {
+        assert!(delay > 0.0, "the delay must be positive");
+        self.delay = delay;
+        self.reset();
+    }
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback;
+    }
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix;
+    }
+
+    /// Clears the delayed signal.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.position = 0;
+    }
+
+    fn delay_frames(&self) -> usize {
+        ((self.delay * self.sample_rate).round() as usize).max(1)
+    }
+}
+
+impl AudioProcessor for Delay {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        let frames = self.delay_frames();
+        if self.buffer.len() != frames * channels {
+            self.buffer.clear();
+            self.buffer.resize(frames * channels, 0.0);
+            self.position = 0;
+        }
+
+        for frame in samples.chunks_exact_mut(channels) {
+            let delayed = &mut self.buffer[self.position * channels..][..channels];
+            for (sample, delayed) in frame.iter_mut().zip(delayed) {
+                let input = *sample;
+                *sample = input * (1.0 - self.mix) + *delayed * self.mix;
+                *delayed = input + *delayed * self.feedback;
+            }
+            self.position = (self.position + 1) % frames;
+        }
+    }
+}
+
+/// Comb filter delays used by the reverb, in frames at 44100 Hz.
+const COMB_TUNINGS: [usize; 4] = [1116, 1188, 1277, 1356];
+/// Allpass filter delays used by the reverb, in frames at 44100 Hz.
+const ALLPASS_TUNINGS: [usize; 2] = [556, 441];
+/// Delay offset between channels, to widen the reverb.
+const STEREO_SPREAD: usize = 23;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filtered: f32
+}
+
+impl Comb {
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.filtered = output * (1.0 - damping) + self.filtered * damping;
+        self.buffer[self.index] = input + self.filtered * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Allpass {
+    buffer: Vec<f32>,
+    index: usize
+}
+
+impl Allpass {
+    fn process(&mut self, input: f32) -> f32 {
+        let delayed = self.buffer[self.index];
+        self.buffer[self.index] = input + delayed * 0.5;
+        self.index = (self.index + 1) % self.buffer.len();
+        delayed - input
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ReverbChannel {
+    combs: [Comb; 4],
+    allpasses: [Allpass; 2]
+}
+
+impl ReverbChannel {
+    fn new(sample_rate: f32, spread: usize) -> Self {
+        let scale = |frames: usize| (((frames + spread) as f32 * sample_rate / 44100.0) as usize).max(1);
+        ReverbChannel {
+            combs: COMB_TUNINGS.map(|frames| Comb { buffer: vec![0.0; scale(frames)], index: 0, filtered: 0.0 }),
+            allpasses: ALLPASS_TUNINGS.map(|frames| Allpass { buffer: vec![0.0; scale(frames)], index: 0 })
+        }
+    }
+}
+
+/// A simple reverb, based on the Schroeder/Moorer design used by Freeverb
+/// (parallel comb filters followed by allpass filters, for each channel).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reverb {
+    sample_rate: f32,
+    room_size: f32,
+    damping: f32,
+    mix: f32,
+    channels: Vec<ReverbChannel>
+}
+
+impl Reverb {
+    /// Creates a reverb with a medium room size, some damping and a 30% mix.
+    /// # Panics
+    /// Panics if `sample_rate` is not positive.
+    pub fn new(sample_rate: f32) -> Self {
+        assert!(sample_rate > 0.0, "the sample rate must be positive");
+        Reverb { sample_rate, room_size: 0.5, damping: 0.5, mix: 0.3, channels: Vec::new() }
+    }
+
+    /// Size of the simulated room, between 0.0 and 1.0 (longer tail).
+    pub fn room_size(&self) -> f32 {
+        self.room_size
+    }
+    /// Absorption of high frequencies, between 0.0 (bright) and 1.0 (dull).
+    pub fn damping(&self) -> f32 {
+        self.damping
+    }
+    /// Balance between the original signal (0.0) and the reverberated signal (1.0).
+    pub fn mix(&self) -> f32 {
+        self.mix
+    }
+
+    pub fn set_room_size(&mut self, room_size: f32) {
+        self.room_size = room_size.clamp(0.0, 1.0);
+    }
+    pub fn set_damping(&mut self, damping: f32) {
+        self.damping = damping.clamp(0.0, 1.0);
+    }
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.clamp(0.0, 1.0);
+    }
+
+    /// Clears the reverb tail.
+    pub fn reset(&mut self) {
+        self.channels.clear();
+    }
+}
+
+impl AudioProcessor for Reverb {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        if self.channels.len() != channels {
+            self.channels = (0..channels).map(|channel| ReverbChannel::new(self.sample_rate, channel * STEREO_SPREAD)).collect();
+        }
+
+        // same scaling as Freeverb
+        let feedback = self.room_size * 0.28 + 0.7;
+        let damping = self.damping * 0.4;
+
+        for frame in samples.chunks_exact_mut(channels) {
+            for (sample, channel) in frame.iter_mut().zip(&mut self.channels) {
+                let input = *sample * 0.03;
+                let mut wet: f32 = channel.combs.iter_mut().map(|comb| comb.process(input, feedback, damping)).sum();
+                for allpass in &mut channel.allpasses {
+                    wet = allpass.process(wet);
+                }
+                *sample = *sample * (1.0 - self.mix) + wet * 3.0 * self.mix;
+            }
+        }
+    }
+}
+
+/// Keeps the signal below a threshold, by lowering its volume when it goes above.
+///
+/// The gain drops instantly on peaks, and goes back up smoothly during the release time.
+/// All channels share the same gain, to keep the stereo image.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut limiter = Limiter::new(44100.0, 0.5, 0.1);
+/// let mut samples = [0.25, 2.0, -1.0, 0.5];
+/// limiter.process(&mut samples, 1);
+/// assert!(samples.iter().all(|sample| sample.abs() <= 0.5));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limiter {
+    sample_rate: f32,
+    threshold: f32,
+    release: f32,
+    /// Per-frame multiplier used to bring the gain back up.
+    release_coefficient: f32,
+    gain: f32
+}
+
+impl Limiter {
+    /// Creates a limiter.
+    /// `threshold` is the maximum amplitude (linear), and `release` is in seconds.
+    ///
+    /// # Panics
+    /// Panics if `sample_rate` or `threshold` are not positive, or if `release` is negative.
+    pub fn new(sample_rate: f32, threshold: f32, release: f32) -> Self {
+        assert!(sample_rate > 0.0, "the sample rate must be positive");
+        let mut limiter = Limiter { sample_rate, threshold: 1.0, release: 0.0, release_coefficient: 0.0, gain: 1.0 };
+        limiter.set_threshold(threshold);
+        limiter.set_release(release);
+        limiter
+    }
+
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+    pub fn release(&self) -> f32 {
+        self.release
+    }
+    /// Gain currently applied to the signal (1.0 when not limiting).
+    pub fn current_gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// # Panics
+    /// Panics if `threshold` is not positive.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        assert!(threshold > 0.0, "the threshold must be positive");
+        self.threshold = threshold;
+    }
+    /// # Panics
+    /// Panics if `release` is negative.
+    pub fn set_release(&mut self, release: f32) {
+        assert!(release >= 0.0, "the release time can't be negative");
+        self.release = release;
+        self.release_coefficient = if release > 0.0 { (-1.0 / (release * self.sample_rate)).exp() } else { 0.0 };
+    }
+}
+
+impl AudioProcessor for Limiter {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        for frame in samples.chunks_exact_mut(channels) {
+            let peak = frame.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+            let target = if peak > self.threshold { self.threshold / peak } else { 1.0 };
+
+            if target < self.gain {
+                self.gain = target;
+            } else {
+                self.gain = target + (self.gain - target) * self.release_coefficient;
+            }
+
+            frame.iter_mut().for_each(|sample| *sample *= self.gain);
+        }
+    }
+}
+
+/// Converts decibels to a linear amplitude factor.
+pub fn db_to_linear(db: f32) -> f32 {
+    10.0f32.powf(db / 20.0)
+}
+
+/// Converts a linear amplitude factor to decibels.
+pub fn linear_to_db(linear: f32) -> f32 {
+    20.0 * linear.log10()
+}
@@ -0,0 +1,268 @@
+//! Audio loading and playing functions (module: `raudio`)
+//!
+//! Everything that plays sound needs an [`AudioDevice`], created with [`init_audio_device`]
+//! (or [`AudioDevice::init`], which doesn't need a window).
+//! [`Wave`]s live on the CPU and can be loaded and edited without any audio device.
+//!
+//! With the `null_audio` feature, raylib only uses miniaudio's null backend:
+//! no sound card is needed, and everything plays silently (in real time) instead.
+//! Use a [`Capture`] processor to read what would have been played.
+
+pub mod wave;
+pub mod sound;
+pub mod music;
+pub mod stream;
+pub mod processor;
+pub mod effects;
+pub mod synth;
+pub mod pool;
+pub mod player;
+pub mod spatial;
+pub mod capture;
+pub mod spectrum;
+mod callback;
+
+use std::{ffi::{c_void, CStr}, path::Path, sync::atomic::{AtomicBool, Ordering}};
+
+use crate::{core::Raylib, cstr, ffi};
+
+pub use wave::Wave;
+pub use sound::Sound;
+pub use music::Music;
+pub use stream::AudioStream;
+pub use processor::{AudioProcessor, ProcessorHandle};
+pub use pool::SoundPool;
+pub use player::MusicPlayer;
+pub use capture::Capture;
+pub use spectrum::{SpectrumAnalyzer, SpectrumInput};
+pub use callback::MAX_AUDIO_CALLBACKS;
+
+/// Keeps track of wether an [`AudioDevice`] currently exists.
+static AUDIO_DEVICE_OPEN: AtomicBool = AtomicBool::new(false);
+
+/// A handle to raylib's audio device.
+///
+/// Every [`Sound`] and [`Music`] borrows the device it was loaded with,
+/// so that the device can't be closed while they are still alive.
+/// The device is closed when this handle is dropped.
+///
+/// Like [`Raylib`], this handle is neither `Send` nor `Sync`.
+pub struct AudioDevice {
+    sample_rate: u32,
+    // disallows initialization from outside and makes the device !Send and !Sync
+    _private: std::marker::PhantomData<*const c_void>
+}
+
+/// Initializes the audio device and context.
+///
+/// Returns [`AudioError::DeviceUnavailable`] if no playback device could be opened,
+/// or if another [`AudioDevice`] handle is still alive.
+/// With the `null_audio` feature, this opens a silent device that works without a sound card.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut init_window(800, 800, "Audio", 60);
+/// let audio = init_audio_device(rl).unwrap();
+/// let sound = Sound::load(&audio, "assets/coin.wav").unwrap();
+/// while !window_should_close(rl) {
+///     if is_key_pressed(rl, Key::Space) {
+///         sound.play();
+///     }
+///     begin_drawing(rl, |rl| clear_background(rl, Color::RAYWHITE));
+///     # break
+/// }
+/// ```
+pub fn init_audio_device(_: &Raylib) -> Result<AudioDevice, AudioError> {
+    AudioDevice::init()
+}
+
+impl AudioDevice {
+    /// Initializes the audio device and context, without a window.
+    ///
+    /// Raylib's audio doesn't depend on its window, so this can be used on machines without a display
+    /// (with the `null_audio` feature, to test audio code on CI for example).
+    /// Otherwise, this is the same as [`init_audio_device`].
+    /// # Example
+    /// ```
+    /// # use raylib::prelude::*;
+    /// let audio = AudioDevice::init().unwrap();
+    /// let wave = SfxParams::coin(0).to_wave(audio.sample_rate());
+    /// let sound = Sound::load_from_wave(&audio, &wave).unwrap();
+    /// sound.play();
+    /// ```
+    pub fn init() -> Result<AudioDevice, AudioError> {
+        if AUDIO_DEVICE_OPEN.swap(true, Ordering::AcqRel) {
+            return Err(AudioError::DeviceUnavailable)
+        }
+
+        unsafe { ffi::InitAudioDevice() }
+        if !unsafe { ffi::IsAudioDeviceReady() } {
+            AUDIO_DEVICE_OPEN.store(false, Ordering::Release);
+            return Err(AudioError::DeviceUnavailable)
+        }
+
+        // NOTE: raylib doesn't expose the device's sample rate, but sounds are converted to it when loaded
+        let wave = Wave::from_samples(44100, 1, &[0i16]);
+        let sound = unsafe { ffi::LoadSoundFromWave(*wave.get_ffi()) };
+        let sample_rate = sound.stream.sampleRate;
+        unsafe { ffi::UnloadSound(sound) }
+
+        Ok(AudioDevice { sample_rate, _private: std::marker::PhantomData })
+    }
+}
+
+impl Drop for AudioDevice {
+    fn drop(&mut self) {
+        unsafe { ffi::CloseAudioDevice() }
+        AUDIO_DEVICE_OPEN.store(false, Ordering::Release);
+    }
+}
+
+impl AudioDevice {
+    /// Sets the master volume (listener), between `0.0` and `1.0`.
+    pub fn set_master_volume(&self, volume: f32) {
+        unsafe { ffi::SetMasterVolume(volume) }
+    }
+
+    /// Gets the master volume (listener).
+    pub fn get_master_volume(&self) -> f32 {
+        unsafe { ffi::GetMasterVolume() }
+    }
+
+    /// The sample rate the device plays at, which is also the rate [`AudioProcessor`]s run at.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+/// An error that can happen when loading audio data.
+/// It converts into the crate's [`Error`](crate::Error), so `?` works in functions returning either.
+#[derive(Debug)]
+pub enum AudioError {
+    /// There was an error when reading the file.
+    Io(std::io::Error),
+    /// The file extension is not a known audio format,
+    /// or this format cannot be used for this kind of object (modules can only be loaded as [`Music`]).
+    UnknownFormat,
+    /// Support for this format was not compiled into raylib.
+    /// Enable the given cargo feature to load it.
+    UnsupportedFormat { feature: &'static str },
+    /// The data could not be decoded.
+    InvalidData,
+    /// The audio device could not be initialized.
+    DeviceUnavailable,
+    /// There are already [`MAX_AUDIO_CALLBACKS`] callbacks and processors registered.
+    TooManyCallbacks
+}
+
+impl std::fmt::Display for AudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioError::Io(e) => write!(f, "io error: {e}"),
+            AudioError::UnknownFormat => write!(f, "unknown audio format"),
+            AudioError::UnsupportedFormat { feature } => write!(f, "support for this audio format was not compiled in (enable the `{feature}` feature)"),
+            AudioError::InvalidData => write!(f, "invalid audio data"),
+            AudioError::DeviceUnavailable => write!(f, "the audio device could not be initialized"),
+            AudioError::TooManyCallbacks => write!(f, "too many audio callbacks and processors are registered")
+        }
+    }
+}
+
+impl std::error::Error for AudioError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AudioError::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<std::io::Error> for AudioError {
+    fn from(value: std::io::Error) -> Self {
+        AudioError::Io(value)
+    }
+}
+
+/// Defines audio filetypes supported by raylib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFiletype {
+    Wav,
+    Ogg,
+    Mp3,
+    Qoa,
+    Flac,
+    /// FastTracker II module (music only)
+    Xm,
+    /// ProTracker module (music only)
+    Mod
+}
+
+impl AudioFiletype {
+    /// Returns the extension corresponding to this filetype as a `&CStr`.
+    /// The resulting string contains a leading dot and is lowercase.
+    pub fn extension(&self) -> &'static CStr {
+        match self {
+            AudioFiletype::Wav => cstr!(".wav"),
+            AudioFiletype::Ogg => cstr!(".ogg"),
+            AudioFiletype::Mp3 => cstr!(".mp3"),
+            AudioFiletype::Qoa => cstr!(".qoa"),
+            AudioFiletype::Flac => cstr!(".flac"),
+            AudioFiletype::Xm => cstr!(".xm"),
+            AudioFiletype::Mod => cstr!(".mod"),
+        }
+    }
+
+    /// The cargo feature that compiles support for this filetype into raylib.
+    pub fn feature(&self) -> &'static str {
+        match self {
+            AudioFiletype::Wav => "wav",
+            AudioFiletype::Ogg => "ogg",
+            AudioFiletype::Mp3 => "mp3",
+            AudioFiletype::Qoa => "qoa",
+            AudioFiletype::Flac => "flac",
+            AudioFiletype::Xm => "xm",
+            AudioFiletype::Mod => "mod",
+        }
+    }
+
+    /// Checks if support for this filetype was compiled into raylib.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            AudioFiletype::Wav => cfg!(feature = "wav"),
+            AudioFiletype::Ogg => cfg!(feature = "ogg"),
+            AudioFiletype::Mp3 => cfg!(feature = "mp3"),
+            AudioFiletype::Qoa => cfg!(feature = "qoa"),
+            AudioFiletype::Flac => cfg!(feature = "flac"),
+            AudioFiletype::Xm => cfg!(feature = "xm"),
+            AudioFiletype::Mod => cfg!(feature = "mod"),
+        }
+    }
+
+    /// Returns wether this filetype can be fully decoded to a [`Wave`] (and thus to a [`Sound`]).
+    /// Tracker modules can only be streamed as [`Music`].
+    pub fn is_wave(&self) -> bool {
+        !matches!(self, AudioFiletype::Xm | AudioFiletype::Mod)
+    }
+
+    /// Guesses the filetype from the extension of the given path (case insensitive).
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        let filetype = match extension.as_str() {
+            "wav" => AudioFiletype::Wav,
+            "ogg" => AudioFiletype::Ogg,
+            "mp3" => AudioFiletype::Mp3,
+            "qoa" => AudioFiletype::Qoa,
+            "flac" => AudioFiletype::Flac,
+            "xm" => AudioFiletype::Xm,
+            "mod" => AudioFiletype::Mod,
+            _ => return None
+        };
+        Some(filetype)
+    }
+
+    /// Returns [`AudioError::UnsupportedFormat`] if support for this filetype was not compiled into raylib.
+    pub(crate) fn check_supported(&self) -> Result<(), AudioError> {
+        if self.is_supported() { Ok(()) }
+        else { Err(AudioError::UnsupportedFormat { feature: self.feature() }) }
+    }
+}
@@ -0,0 +1,163 @@
+use std::{ffi::{CStr, CString}, marker::PhantomData};
+
+use crate::ffi;
+
+use super::{AudioDevice, AudioError, AudioFiletype};
+
+/// An audio stream decoded progressively while it plays.
+/// Use [`Music::load`] to create one.
+///
+/// Anything longer than ~10 seconds should be streamed rather than loaded as a [`Sound`](super::Sound).
+/// [`Music::update`] needs to be called regularly (every frame) to refill the stream buffers.
+///
+/// A music borrows the [`AudioDevice`] it was loaded with, and is unloaded when dropped.
+#[derive(Debug)]
+pub struct Music<'a> {
+    music: ffi::Music,
+    /// Encoded data the decoder reads from, when loaded from memory.
+    /// NOTE: raylib doesn't copy the buffer, so it needs to outlive the music stream.
+    _data: Option<Box<[u8]>>,
+    _device: PhantomData<&'a AudioDevice>
+}
+
+impl Drop for Music<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::UnloadMusicStream(self.music) }
+    }
+}
+
+impl ffi::Music {
+    /// Checks if the music metadata is valid.
+    /// Equivalent to `IsMusicReady`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::IsMusicReady(*self) }
+    }
+}
+
+/// # Music loading functions
+///
+/// ---
+impl<'a> Music<'a> {
+    /// Loads a music stream from a file.
+    /// The file is read progressively while the music plays.
+    ///
+    /// Supports `wav`, `ogg`, `mp3`, `qoa`, `flac`, `xm` and `mod` files (if they were compiled into raylib).
+    /// Returns [`AudioError::UnsupportedFormat`] if support for the file's format was compiled out.
+    pub fn load(audio: &'a AudioDevice, filename: impl AsRef<std::path::Path>) -> Result<Music<'a>, AudioError> {
+        let filename = filename.as_ref();
+        let filetype = AudioFiletype::from_path(filename).ok_or(AudioError::UnknownFormat)?;
+        filetype.check_supported()?;
+
+        // raylib's decoders open the file themselves, bypassing the file callbacks
+        if crate::core::vfs::has_vfs() {
+            let data = crate::core::vfs::read(filename)?;
+            return Music::load_from_memory(audio, filetype, data)
+        }
+
+        if !filename.exists() { return Err(std::io::Error::from(std::io::ErrorKind::NotFound).into()) }
+        let cstr = CString::new(filename.as_os_str().as_encoded_bytes()).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        Music::load_cstr(audio, &cstr)
+    }
+
+    /// Loads a music stream from a file.
+    /// Unlike [`Music::load`], this doesn't check if the format is supported beforehand.
+    pub fn load_cstr(_audio: &'a AudioDevice, filename: &CStr) -> Result<Music<'a>, AudioError> {
+        let music = unsafe { ffi::LoadMusicStream(filename.as_ptr()) };
+        if !music.is_valid() { return Err(AudioError::InvalidData) }
+
+        Ok(Music { music, _data: None, _device: PhantomData })
+    }
+
+    /// Loads a music stream from a memory buffer (in the given filetype's encoding).
+    /// The music takes ownership of the buffer, since it is decoded while playing.
+    pub fn load_from_memory(_audio: &'a AudioDevice, filetype: AudioFiletype, data: impl Into<Box<[u8]>>) -> Result<Music<'a>, AudioError> {
+        filetype.check_supported()?;
+
+        let data = data.into();
+        let music = unsafe { ffi::LoadMusicStreamFromMemory(filetype.extension().as_ptr(), data.as_ptr(), data.len() as i32) };
+        if !music.is_valid() { return Err(AudioError::InvalidData) }
+
+        Ok(Music { music, _data: Some(data), _device: PhantomData })
+    }
+}
+
+impl Music<'_> {
+    /// Starts playing the music.
+    #[inline]
+    pub fn play(&self) {
+        unsafe { ffi::PlayMusicStream(self.music) }
+    }
+    /// Checks if the music is playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsMusicStreamPlaying(self.music) }
+    }
+    /// Refills the stream buffers with newly decoded data.
+    /// This needs to be called every frame while the music is playing.
+    #[inline]
+    pub fn update(&self) {
+        unsafe { ffi::UpdateMusicStream(self.music) }
+    }
+    /// Stops the music and rewinds it to the start.
+    #[inline]
+    pub fn stop(&self) {
+        unsafe { ffi::StopMusicStream(self.music) }
+    }
+    /// Pauses the music.
+    #[inline]
+    pub fn pause(&self) {
+        unsafe { ffi::PauseMusicStream(self.music) }
+    }
+    /// Resumes a paused music.
+    #[inline]
+    pub fn resume(&self) {
+        unsafe { ffi::ResumeMusicStream(self.music) }
+    }
+    /// Seeks the music to a position (in seconds).
+    #[inline]
+    pub fn seek(&self, position: f32) {
+        unsafe { ffi::SeekMusicStream(self.music, position) }
+    }
+    /// Sets the volume of the music (1.0 is the max level).
+    #[inline]
+    pub fn set_volume(&self, volume: f32) {
+        unsafe { ffi::SetMusicVolume(self.music, volume) }
+    }
+    /// Sets the pitch of the music (1.0 is the base level).
+    #[inline]
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe { ffi::SetMusicPitch(self.music, pitch) }
+    }
+    /// Sets the pan of the music (0.5 is the center, 1.0 is fully left and 0.0 fully right).
+    #[inline]
+    pub fn set_pan(&self, pan: f32) {
+        unsafe { ffi::SetMusicPan(self.music, pan) }
+    }
+    /// Gets the total length of the music (in seconds).
+    #[inline]
+    pub fn time_length(&self) -> f32 {
+        unsafe { ffi::GetMusicTimeLength(self.music) }
+    }
+    /// Gets the current position in the music (in seconds).
+    #[inline]
+    pub fn time_played(&self) -> f32 {
+        unsafe { ffi::GetMusicTimePlayed(self.music) }
+    }
+    /// Checks if the music loops back to the start when it ends.
+    #[inline]
+    pub fn looping(&self) -> bool {
+        self.music.looping
+    }
+    /// Sets wether the music loops back to the start when it ends (on by default).
+    #[inline]
+    pub fn set_looping(&mut self, looping: bool) {
+        self.music.looping = looping;
+    }
+
+    /// Get a reference to the underlying ffi music
+    #[inline]
+    pub fn get_ffi(&self) -> &ffi::Music {
+        &self.music
+    }
+}
@@ -0,0 +1,374 @@
+use super::Music;
+
+/// A linear ramp between two values over time, used for volume fades.
+///
+/// Fades don't read any clock, they are advanced by the elapsed time given to [`Fade::advance`].
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut fade = Fade::new(1.0, 0.0, 2.0);
+/// fade.advance(0.5);
+/// assert_eq!(fade.value(), 0.75);
+/// fade.advance(10.0);
+/// assert!(fade.is_finished());
+/// assert_eq!(fade.value(), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fade {
+    from: f32,
+    to: f32,
+    duration: f32,
+    elapsed: f32
+}
+
+impl Fade {
+    /// Creates a fade from `from` to `to`, lasting `duration` seconds.
+    pub fn new(from: f32, to: f32, duration: f32) -> Self {
+        Fade { from, to, duration, elapsed: 0.0 }
+    }
+
+    /// Moves the fade forward by `dt` seconds.
+    pub fn advance(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+    /// Current value of the fade.
+    pub fn value(&self) -> f32 {
+        if self.duration <= 0.0 { return self.to }
+        self.from + (self.to - self.from) * (self.elapsed / self.duration)
+    }
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// What a [`MusicPlayer`] does when a track ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// Plays the next track, and stops after the last one.
+    Off,
+    /// Loops the current track.
+    One,
+    /// Plays the next track, and goes back to the first one after the last one.
+    #[default]
+    All
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Stopped,
+    Playing,
+    Paused
+}
+
+/// Plays a list of [`Music`] tracks one after the other, with optional crossfades.
+///
+/// [`MusicPlayer::update`] needs to be called every frame, with the time elapsed since the last call.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut init_window(800, 800, "Music", 60);
+/// let audio = init_audio_device(rl).unwrap();
+/// let mut player = MusicPlayer::new();
+/// player.push(Music::load(&audio, "assets/coin.wav").unwrap());
+/// player.push(Music::load(&audio, "assets/coin.wav").unwrap());
+/// player.set_crossfade(2.0);
+/// player.play();
+/// while !window_should_close(rl) {
+///     player.update(get_frame_time(rl));
+///     if is_key_pressed(rl, Key::N) {
+///         player.next();
+///     }
+///     begin_drawing(rl, |rl| clear_background(rl, Color::RAYWHITE));
+///     # break
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MusicPlayer<'a> {
+    playlist: Vec<Music<'a>>,
+    current: Option<usize>,
+    /// Fade of the current track.
+    fade_in: Option<Fade>,
+    /// Previous track, fading out during a crossfade.
+    fade_out: Option<(usize, Fade)>,
+    crossfade: f32,
+    repeat: Repeat,
+    volume: f32,
+    state: State
+}
+
+impl Default for MusicPlayer<'_> {
+    fn default() -> Self {
+        MusicPlayer::new()
+    }
+}
+
+impl<'a> MusicPlayer<'a> {
+    /// Creates an empty player, repeating the whole playlist without crossfades.
+    pub fn new() -> Self {
+        MusicPlayer {
+            playlist: Vec::new(),
+            current: None,
+            fade_in: None,
+            fade_out: None,
+            crossfade: 0.0,
+            repeat: Repeat::All,
+            volume: 1.0,
+            state: State::Stopped
+        }
+    }
+
+    /// Adds a track at the end of the playlist.
+    /// Returns its index.
+    pub fn push(&mut self, mut music: Music<'a>) -> usize {
+        music.set_looping(self.repeat == Repeat::One);
+        self.playlist.push(music);
+        self.playlist.len() - 1
+    }
+
+    /// Stops playback and removes every track.
+    pub fn clear(&mut self) {
+        self.stop();
+        self.playlist.clear();
+        self.current = None;
+    }
+
+    /// The tracks in the playlist.
+    pub fn playlist(&self) -> &[Music<'a>] {
+        &self.playlist
+    }
+}
+
+impl MusicPlayer<'_> {
+    /// Starts playing, or resumes if paused.
+    /// Starts from the first track if nothing was playing before.
+    pub fn play(&mut self) {
+        match self.state {
+            State::Paused => self.resume(),
+            State::Playing => (),
+            State::Stopped => if !self.playlist.is_empty() {
+                self.start(self.current.unwrap_or(0), 0.0)
+            }
+        }
+    }
+
+    /// Switches to the track at `index`, crossfading if something is playing.
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn play_index(&mut self, index: usize) {
+        assert!(index < self.playlist.len(), "track index out of bounds");
+        let crossfade = match self.current_music() {
+            Some(music) if self.state == State::Playing => self.crossfade_for(music),
+            _ => 0.0
+        };
+        self.start(index, crossfade)
+    }
+
+    /// Switches to the next track (going back to the first one after the last one).
+    pub fn next(&mut self) {
+        if self.playlist.is_empty() { return }
+        let index = self.current.map_or(0, |current| (current + 1) % self.playlist.len());
+        self.play_index(index)
+    }
+
+    /// Switches to the previous track (going to the last one from the first one).
+    pub fn previous(&mut self) {
+        if self.playlist.is_empty() { return }
+        let len = self.playlist.len();
+        let index = self.current.map_or(0, |current| (current + len - 1) % len);
+        self.play_index(index)
+    }
+
+    /// Stops playback, rewinding the current track.
+    pub fn stop(&mut self) {
+        if let Some((previous, _)) = self.fade_out.take() {
+            self.playlist[previous].stop();
+        }
+        if let Some(current) = self.current {
+            self.playlist[current].stop();
+        }
+        self.fade_in = None;
+        self.state = State::Stopped;
+    }
+
+    /// Pauses playback (and any crossfade in progress).
+    pub fn pause(&mut self) {
+        if self.state != State::Playing { return }
+        self.for_each_active(|music| music.pause());
+        self.state = State::Paused;
+    }
+
+    /// Resumes paused playback.
+    pub fn resume(&mut self) {
+        if self.state != State::Paused { return }
+        self.for_each_active(|music| music.resume());
+        self.state = State::Playing;
+    }
+
+    /// Refills the music buffers, advances fades and moves to the next track when needed.
+    /// Call this every frame with the time elapsed since the previous call (see [`get_frame_time`](crate::core::other::get_frame_time)).
+    pub fn update(&mut self, dt: f32) {
+        if self.state != State::Playing { return }
+        self.for_each_active(|music| music.update());
+
+        if let Some((previous, fade)) = &mut self.fade_out {
+            fade.advance(dt);
+            let music = &self.playlist[*previous];
+            if fade.is_finished() {
+                music.stop();
+                self.fade_out = None;
+            } else {
+                music.set_volume(fade.value() * self.volume);
+            }
+        }
+
+        let Some(current) = self.current else { return };
+        if let Some(fade) = &mut self.fade_in {
+            fade.advance(dt);
+            self.playlist[current].set_volume(fade.value() * self.volume);
+            if fade.is_finished() { self.fade_in = None }
+        }
+
+        if self.repeat == Repeat::One { return }
+
+        let music = &self.playlist[current];
+        let is_last = current + 1 == self.playlist.len();
+        if self.repeat == Repeat::Off && is_last {
+            if !music.is_playing() { self.stop() }
+            return
+        }
+
+        let crossfade = self.crossfade_for(music);
+        let remaining = music.time_length() - music.time_played();
+        if crossfade > 0.0 && self.fade_out.is_none() && remaining <= crossfade {
+            self.start_next(crossfade);
+        } else if !music.is_playing() {
+            self.start_next(0.0);
+        }
+    }
+
+    /// Seeks the current track to a position (in seconds).
+    pub fn seek(&self, position: f32) {
+        if let Some(current) = self.current {
+            self.playlist[current].seek(position);
+        }
+    }
+
+    /// Current position in the current track (in seconds).
+    pub fn time_played(&self) -> Option<f32> {
+        self.current_music().map(|music| music.time_played())
+    }
+    /// Total length of the current track (in seconds).
+    pub fn time_length(&self) -> Option<f32> {
+        self.current_music().map(|music| music.time_length())
+    }
+    /// Progress in the current track, between 0.0 and 1.0.
+    pub fn progress(&self) -> Option<f32> {
+        self.current_music().map(|music| {
+            let length = music.time_length();
+            if length > 0.0 { (music.time_played() / length).clamp(0.0, 1.0) } else { 0.0 }
+        })
+    }
+
+    /// Index of the current track.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+    pub fn current_music(&self) -> Option<&Music<'_>> {
+        self.current.map(|current| &self.playlist[current])
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.state == State::Playing
+    }
+    pub fn is_paused(&self) -> bool {
+        self.state == State::Paused
+    }
+    /// Checks if two tracks are currently crossfading.
+    pub fn is_crossfading(&self) -> bool {
+        self.fade_out.is_some()
+    }
+
+    /// Sets the duration of crossfades between tracks (in seconds, 0.0 to disable them).
+    pub fn set_crossfade(&mut self, seconds: f32) {
+        self.crossfade = seconds.max(0.0);
+    }
+    pub fn crossfade(&self) -> f32 {
+        self.crossfade
+    }
+
+    pub fn set_repeat(&mut self, repeat: Repeat) {
+        self.repeat = repeat;
+        for music in &mut self.playlist {
+            music.set_looping(repeat == Repeat::One);
+        }
+    }
+    pub fn repeat(&self) -> Repeat {
+        self.repeat
+    }
+
+    /// Sets the volume of the player (1.0 is the max level).
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+        if let (Some(current), None) = (self.current, self.fade_in) {
+            self.playlist[current].set_volume(volume);
+        }
+    }
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Duration of a crossfade out of `music`.
+    fn crossfade_for(&self, music: &Music) -> f32 {
+        // tracks shorter than two crossfades fade for half of their length instead
+        self.crossfade.min(music.time_length() / 2.0)
+    }
+
+    fn start_next(&mut self, crossfade: f32) {
+        let Some(current) = self.current else { return };
+        self.start((current + 1) % self.playlist.len(), crossfade)
+    }
+
+    /// Starts the track at `index` from the beginning.
+    /// If `crossfade` is positive, the current track fades out while the new one fades in, for that many seconds.
+    fn start(&mut self, index: usize, crossfade: f32) {
+        // a crossfade was already in progress, cut the oldest track
+        if let Some((previous, _)) = self.fade_out.take() {
+            self.playlist[previous].stop();
+        }
+
+        let fade = crossfade > 0.0;
+        match self.current {
+            Some(current) if current != index => if fade {
+                let volume = self.fade_in.map_or(1.0, |fade| fade.value());
+                self.fade_out = Some((current, Fade::new(volume, 0.0, crossfade)));
+            } else {
+                self.playlist[current].stop();
+            },
+            // restarting the same track can't be crossfaded
+            Some(current) => self.playlist[current].stop(),
+            None => ()
+        }
+
+        let music = &self.playlist[index];
+        if fade {
+            self.fade_in = Some(Fade::new(0.0, 1.0, crossfade));
+            music.set_volume(0.0);
+        } else {
+            self.fade_in = None;
+            music.set_volume(self.volume);
+        }
+        music.play();
+
+        self.current = Some(index);
+        self.state = State::Playing;
+    }
+
+    fn for_each_active(&self, f: impl Fn(&Music)) {
+        if let Some((previous, _)) = self.fade_out {
+            f(&self.playlist[previous]);
+        }
+        if let Some(current) = self.current {
+            f(&self.playlist[current]);
+        }
+    }
+}
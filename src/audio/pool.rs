@@ -0,0 +1,180 @@
+use std::ops::RangeInclusive;
+
+use crate::ffi;
+
+use super::{synth::Rng, AudioDevice, AudioError, Sound};
+
+/// Plays the same sound several times at once (gunfire, footsteps, ...).
+///
+/// Playing a [`Sound`] that is already playing restarts it.
+/// A pool instead loads aliases of a base sound (sharing its sample data),
+/// and plays each request on a different voice.
+/// When every voice is busy, the one that started the earliest is restarted.
+///
+/// Each play can be randomized in pitch, volume and pan, to make repeated sounds less monotonous.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// # let rl = &mut init_window(100, 100, "pool", 60);
+/// let audio = init_audio_device(rl).unwrap();
+/// let mut steps = SoundPool::load(&audio, "assets/coin.wav", 4).unwrap();
+/// steps.set_pitch_range(0.9..=1.1);
+/// steps.set_volume_range(0.7..=1.0);
+/// for _ in 0..8 {
+///     steps.play();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SoundPool<'a> {
+    /// NOTE: declared before `base`, aliases need to be unloaded first.
+    voices: Voices,
+    base: Sound<'a>,
+    /// Voice to try first on the next play.
+    next: usize,
+    /// Play counter, used to find the oldest voice.
+    plays: u64,
+    pitch: RangeInclusive<f32>,
+    volume: RangeInclusive<f32>,
+    pan: RangeInclusive<f32>,
+    rng: Rng
+}
+
+/// Sound aliases, unloaded on drop.
+#[derive(Debug)]
+struct Voices {
+    aliases: Vec<ffi::Sound>,
+    /// Value of the play counter when each voice was last started.
+    started: Vec<u64>
+}
+
+impl Drop for Voices {
+    fn drop(&mut self) {
+        for alias in &self.aliases {
+            unsafe { ffi::UnloadSoundAlias(*alias) }
+        }
+    }
+}
+
+impl<'a> SoundPool<'a> {
+    /// Creates a pool with `voices` aliases of the given sound.
+    ///
+    /// # Panics
+    /// Panics if `voices` is 0.
+    pub fn new(base: Sound<'a>, voices: usize) -> Result<SoundPool<'a>, AudioError> {
+        assert!(voices > 0, "a sound pool needs at least one voice");
+
+        let mut pool = Voices { aliases: Vec::with_capacity(voices), started: vec![0; voices] };
+        for _ in 0..voices {
+            let alias = unsafe { ffi::LoadSoundAlias(*base.get_ffi()) };
+            if !alias.is_valid() { return Err(AudioError::InvalidData) }
+            pool.aliases.push(alias);
+        }
+
+        Ok(SoundPool {
+            voices: pool,
+            base,
+            next: 0,
+            plays: 0,
+            pitch: 1.0..=1.0,
+            volume: 1.0..=1.0,
+            pan: 0.5..=0.5,
+            rng: Rng::new(0)
+        })
+    }
+
+    /// Loads a sound from a file, and creates a pool with `voices` aliases of it.
+    ///
+    /// # Panics
+    /// Panics if `voices` is 0.
+    pub fn load(audio: &'a AudioDevice, filename: impl AsRef<std::path::Path>, voices: usize) -> Result<SoundPool<'a>, AudioError> {
+        SoundPool::new(Sound::load(audio, filename)?, voices)
+    }
+}
+
+impl<'a> SoundPool<'a> {
+    /// Plays the sound on the next free voice, or restarts the oldest one if all of them are playing.
+    /// Returns the index of the voice used.
+    pub fn play(&mut self) -> usize {
+        let count = self.voices.aliases.len();
+        let voice = (0..count)
+            .map(|offset| (self.next + offset) % count)
+            .find(|&voice| !unsafe { ffi::IsSoundPlaying(self.voices.aliases[voice]) })
+            .unwrap_or_else(|| (0..count).min_by_key(|&voice| self.voices.started[voice]).unwrap());
+
+        let alias = self.voices.aliases[voice];
+        let pitch = self.random(self.pitch.clone());
+        let volume = self.random(self.volume.clone());
+        let pan = self.random(self.pan.clone());
+        unsafe {
+            ffi::SetSoundPitch(alias, pitch);
+            ffi::SetSoundVolume(alias, volume);
+            ffi::SetSoundPan(alias, pan);
+            ffi::PlaySound(alias);
+        }
+
+        self.plays += 1;
+        self.voices.started[voice] = self.plays;
+        self.next = (voice + 1) % count;
+        voice
+    }
+
+    /// Stops a single voice.
+    /// # Panics
+    /// Panics if `voice` is not smaller than [`SoundPool::voice_count`].
+    pub fn stop_voice(&self, voice: usize) {
+        unsafe { ffi::StopSound(self.voices.aliases[voice]) }
+    }
+    /// Stops every voice.
+    pub fn stop(&self) {
+        for alias in &self.voices.aliases {
+            unsafe { ffi::StopSound(*alias) }
+        }
+    }
+    /// Checks if a voice is playing.
+    /// # Panics
+    /// Panics if `voice` is not smaller than [`SoundPool::voice_count`].
+    pub fn is_voice_playing(&self, voice: usize) -> bool {
+        unsafe { ffi::IsSoundPlaying(self.voices.aliases[voice]) }
+    }
+    /// Number of voices currently playing.
+    pub fn playing_count(&self) -> usize {
+        self.voices.aliases.iter().filter(|alias| unsafe { ffi::IsSoundPlaying(**alias) }).count()
+    }
+    /// Total number of voices.
+    pub fn voice_count(&self) -> usize {
+        self.voices.aliases.len()
+    }
+
+    /// Sets the range the pitch of each play is picked from (1.0 is the base level).
+    pub fn set_pitch_range(&mut self, pitch: RangeInclusive<f32>) {
+        self.pitch = pitch;
+    }
+    /// Sets the range the volume of each play is picked from (1.0 is the max level).
+    pub fn set_volume_range(&mut self, volume: RangeInclusive<f32>) {
+        self.volume = volume;
+    }
+    /// Sets the range the pan of each play is picked from (0.5 is the center, 1.0 is fully left and 0.0 fully right).
+    pub fn set_pan_range(&mut self, pan: RangeInclusive<f32>) {
+        self.pan = pan;
+    }
+    /// Reseeds the generator used to randomize plays.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    /// The sound the voices are aliases of.
+    pub fn base(&self) -> &Sound<'a> {
+        &self.base
+    }
+
+    /// Gives the base sound back, unloading the voices.
+    pub fn into_base(self) -> Sound<'a> {
+        drop(self.voices);
+        self.base
+    }
+
+    fn random(&mut self, range: RangeInclusive<f32>) -> f32 {
+        let (min, max) = range.into_inner();
+        if min == max { min } else { self.rng.range(min, max) }
+    }
+}
@@ -0,0 +1,177 @@
+use std::{marker::PhantomData, mem::ManuallyDrop, sync::{Arc, Mutex, MutexGuard}};
+
+use crate::ffi;
+
+use super::{callback::{self, Owner, SlotId}, AudioDevice, AudioError, AudioStream, Music, Sound};
+
+/// Number of channels processors are called with.
+/// Raylib converts every stream to the device's format (stereo 32 bit floats) before processing and mixing it.
+pub const PROCESSOR_CHANNELS: usize = 2;
+
+/// An effect applied to audio data, either on a single stream or on the mixed output of the device.
+///
+/// Processors work on interleaved `f32` samples, and don't depend on the audio device:
+/// they can be run on plain buffers (to render audio offline, or to test them).
+/// When attached to the device, they are called from the audio thread with [`PROCESSOR_CHANNELS`] channels,
+/// at the sample rate given by [`AudioDevice::sample_rate`].
+/// A processor that panics on the audio thread stops being called (its samples are left unprocessed).
+///
+/// Any `FnMut(&mut [f32], usize)` closure is also a processor.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut gain = Gain::new(0.5);
+/// let mut samples = [1.0, -1.0, 0.5, -0.5];
+/// gain.process(&mut samples, 2);
+/// assert_eq!(samples, [0.5, -0.5, 0.25, -0.25]);
+/// ```
+pub trait AudioProcessor {
+    /// Processes interleaved samples in place.
+    /// `samples.len()` is always a multiple of `channels`.
+    fn process(&mut self, samples: &mut [f32], channels: usize);
+}
+
+impl<F: FnMut(&mut [f32], usize)> AudioProcessor for F {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        self(samples, channels)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Stream(ffi::AudioStream),
+    Mixed
+}
+
+/// A processor attached to a stream or to the device.
+/// The processor is detached when the handle is dropped.
+///
+/// Created with [`AudioStream::attach_processor`], [`Sound::attach_processor`],
+/// [`Music::attach_processor`] or [`AudioDevice::attach_mixed_processor`].
+#[derive(Debug)]
+pub struct ProcessorHandle<'a, P> {
+    target: Target,
+    slot: SlotId,
+    processor: Arc<Mutex<P>>,
+    _target: PhantomData<&'a ()>
+}
+
+impl<P: AudioProcessor + Send + 'static> ProcessorHandle<'_, P> {
+    fn attach(target: Target, processor: P) -> Result<Self, AudioError> {
+        let processor = Arc::new(Mutex::new(processor));
+
+        let shared = processor.clone();
+        let raw: callback::RawCallback = Box::new(move |data, frames| {
+            // SAFETY: raylib gives a buffer of `frames` frames in the mixing format (stereo 32 bit floats)
+            let samples = unsafe { std::slice::from_raw_parts_mut(data as *mut f32, frames as usize * PROCESSOR_CHANNELS) };
+            if let Ok(mut processor) = shared.lock() {
+                processor.process(samples, PROCESSOR_CHANNELS)
+            }
+        });
+
+        let slot = match target {
+            Target::Stream(stream) => {
+                let slot = callback::register(Owner::StreamProcessor(stream.buffer as usize), raw)?;
+                unsafe { ffi::AttachAudioStreamProcessor(stream, slot.trampoline()) }
+                slot
+            }
+            Target::Mixed => {
+                let slot = callback::register(Owner::MixedProcessor, raw)?;
+                unsafe { ffi::AttachAudioMixedProcessor(slot.trampoline()) }
+                slot
+            }
+        };
+
+        Ok(ProcessorHandle { target, slot, processor, _target: PhantomData })
+    }
+}
+
+impl<P> ProcessorHandle<'_, P> {
+    /// Locks the processor, to change its parameters.
+    ///
+    /// The audio thread waits while the guard is alive, so it should be dropped quickly.
+    pub fn lock(&self) -> MutexGuard<'_, P> {
+        self.processor.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Detaches the processor and gives it back.
+    pub fn detach(self) -> P {
+        let mut this = ManuallyDrop::new(self);
+        this.detach_raw();
+        // SAFETY: `this` is never used or dropped again
+        let processor = unsafe { std::ptr::read(&this.processor) };
+
+        // the registry dropped its reference when the slot was released
+        let processor = Arc::into_inner(processor).expect("processor still referenced by the audio thread");
+        processor.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn detach_raw(&mut self) {
+        match self.target {
+            Target::Stream(stream) => unsafe { ffi::DetachAudioStreamProcessor(stream, self.slot.trampoline()) },
+            Target::Mixed => unsafe { ffi::DetachAudioMixedProcessor(self.slot.trampoline()) }
+        }
+        callback::release(self.slot)
+    }
+}
+
+impl<P> Drop for ProcessorHandle<'_, P> {
+    fn drop(&mut self) {
+        self.detach_raw()
+    }
+}
+
+impl AudioDevice {
+    /// Attaches a processor to the mixed output of the device.
+    /// It is applied to every sound, music and stream, after mixing them together.
+    ///
+    /// Processors are called in the order they were attached.
+    /// Returns [`AudioError::TooManyCallbacks`] if there are already [`MAX_AUDIO_CALLBACKS`](super::MAX_AUDIO_CALLBACKS)
+    /// callbacks and processors registered.
+    /// # Example
+    /// Muffle everything while the game is paused:
+    /// ```
+    /// # use raylib::prelude::*;
+    /// # let rl = &mut init_window(100, 100, "processor", 60);
+    /// let audio = init_audio_device(rl).unwrap();
+    /// let muffle = audio.attach_mixed_processor(Biquad::low_pass(audio.sample_rate() as f32, 600.0, 0.707)).unwrap();
+    /// // ...
+    /// drop(muffle); // back to normal
+    /// ```
+    pub fn attach_mixed_processor<P: AudioProcessor + Send + 'static>(&self, processor: P) -> Result<ProcessorHandle<'_, P>, AudioError> {
+        ProcessorHandle::attach(Target::Mixed, processor)
+    }
+}
+
+impl AudioStream<'_> {
+    /// Attaches a processor to this stream.
+    ///
+    /// Processors are called in the order they were attached.
+    /// Returns [`AudioError::TooManyCallbacks`] if there are already [`MAX_AUDIO_CALLBACKS`](super::MAX_AUDIO_CALLBACKS)
+    /// callbacks and processors registered.
+    pub fn attach_processor<P: AudioProcessor + Send + 'static>(&self, processor: P) -> Result<ProcessorHandle<'_, P>, AudioError> {
+        ProcessorHandle::attach(Target::Stream(*self.get_ffi()), processor)
+    }
+}
+
+impl Sound<'_> {
+    /// Attaches a processor to this sound.
+    ///
+    /// Processors are called in the order they were attached.
+    /// Returns [`AudioError::TooManyCallbacks`] if there are already [`MAX_AUDIO_CALLBACKS`](super::MAX_AUDIO_CALLBACKS)
+    /// callbacks and processors registered.
+    pub fn attach_processor<P: AudioProcessor + Send + 'static>(&self, processor: P) -> Result<ProcessorHandle<'_, P>, AudioError> {
+        ProcessorHandle::attach(Target::Stream(self.get_ffi().stream), processor)
+    }
+}
+
+impl Music<'_> {
+    /// Attaches a processor to this music.
+    ///
+    /// Processors are called in the order they were attached.
+    /// Returns [`AudioError::TooManyCallbacks`] if there are already [`MAX_AUDIO_CALLBACKS`](super::MAX_AUDIO_CALLBACKS)
+    /// callbacks and processors registered.
+    pub fn attach_processor<P: AudioProcessor + Send + 'static>(&self, processor: P) -> Result<ProcessorHandle<'_, P>, AudioError> {
+        ProcessorHandle::attach(Target::Stream(self.get_ffi().stream), processor)
+    }
+}
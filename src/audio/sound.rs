@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+
+use crate::ffi;
+
+use super::{AudioDevice, AudioError, AudioFiletype, Wave};
+
+/// A sound fully loaded into memory, ready to be played.
+/// Use [`Sound::load`] to create one.
+///
+/// Sounds are meant for short sound effects.
+/// For anything longer than ~10 seconds, prefer streaming a [`Music`](super::Music).
+///
+/// A sound borrows the [`AudioDevice`] it was loaded with, and is unloaded when dropped.
+#[derive(Debug)]
+pub struct Sound<'a>(ffi::Sound, PhantomData<&'a AudioDevice>);
+
+impl Drop for Sound<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::UnloadSound(self.0) }
+    }
+}
+
+impl ffi::Sound {
+    /// Checks if the sound metadata is valid.
+    /// Equivalent to `IsSoundReady`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::IsSoundReady(*self) }
+    }
+}
+
+/// # Sound loading functions
+///
+/// ---
+impl<'a> Sound<'a> {
+    /// Loads a sound from a file.
+    ///
+    /// Supports `wav`, `ogg`, `mp3`, `qoa` and `flac` files (if they were compiled into raylib).
+    /// Returns [`AudioError::UnsupportedFormat`] if support for the file's format was compiled out.
+    pub fn load(audio: &'a AudioDevice, filename: impl AsRef<std::path::Path>) -> Result<Sound<'a>, AudioError> {
+        let wave = Wave::load(filename)?;
+        Sound::load_from_wave(audio, &wave)
+    }
+
+    /// Loads a sound from a memory buffer (in the given filetype's encoding).
+    pub fn load_from_memory(audio: &'a AudioDevice, filetype: AudioFiletype, data: &[u8]) -> Result<Sound<'a>, AudioError> {
+        let wave = Wave::load_from_memory(filetype, data)?;
+        Sound::load_from_wave(audio, &wave)
+    }
+
+    /// Loads a sound from wave data.
+    /// The wave data is copied, so the wave can be dropped or reused afterwards.
+    pub fn load_from_wave(_audio: &'a AudioDevice, wave: &Wave) -> Result<Sound<'a>, AudioError> {
+        let sound = unsafe { ffi::LoadSoundFromWave(*wave.get_ffi()) };
+        if !sound.is_valid() { return Err(AudioError::InvalidData) }
+        Ok(Sound(sound, PhantomData))
+    }
+}
+
+impl Sound<'_> {
+    /// Plays the sound from the start.
+    /// If the sound was already playing, it is restarted.
+    #[inline]
+    pub fn play(&self) {
+        unsafe { ffi::PlaySound(self.0) }
+    }
+    /// Stops playing the sound.
+    #[inline]
+    pub fn stop(&self) {
+        unsafe { ffi::StopSound(self.0) }
+    }
+    /// Pauses the sound.
+    #[inline]
+    pub fn pause(&self) {
+        unsafe { ffi::PauseSound(self.0) }
+    }
+    /// Resumes a paused sound.
+    #[inline]
+    pub fn resume(&self) {
+        unsafe { ffi::ResumeSound(self.0) }
+    }
+    /// Checks if the sound is currently playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsSoundPlaying(self.0) }
+    }
+    /// Sets the volume of the sound (1.0 is the max level).
+    #[inline]
+    pub fn set_volume(&self, volume: f32) {
+        unsafe { ffi::SetSoundVolume(self.0, volume) }
+    }
+    /// Sets the pitch of the sound (1.0 is the base level).
+    #[inline]
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe { ffi::SetSoundPitch(self.0, pitch) }
+    }
+    /// Sets the pan of the sound (0.5 is the center, 1.0 is fully left and 0.0 fully right).
+    #[inline]
+    pub fn set_pan(&self, pan: f32) {
+        unsafe { ffi::SetSoundPan(self.0, pan) }
+    }
+
+    /// Total number of frames in the sound.
+    #[inline]
+    pub fn frame_count(&self) -> u32 {
+        self.0.frameCount
+    }
+
+    /// Get a reference to the underlying ffi sound
+    #[inline]
+    pub fn get_ffi(&self) -> &ffi::Sound {
+        &self.0
+    }
+}
@@ -0,0 +1,202 @@
+//! Positional audio helpers.
+//!
+//! Computes the volume and pan of a sound from the position of its emitter relative to a [`Listener`].
+//! The math ([`Attenuation::gain`], [`spatialize`]) doesn't depend on the audio device,
+//! and [`SpatialSound`] applies it to a [`Sound`].
+//!
+//! Positions are 3d, 2d games can use `position.vec3(0.0)` with a listener made by [`Listener::from_camera_2d`].
+
+use crate::{ffi::{Camera2D, Camera3D}, math::{vector2::Vector2, vector3::{vec3, Vector3}}};
+
+use super::Sound;
+
+/// The point of view sounds are heard from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Listener {
+    pub position: Vector3,
+    /// Unit vector pointing to the right of the listener (towards the right speaker).
+    pub right: Vector3
+}
+
+impl Listener {
+    /// Creates a listener at `position`, looking towards `forward`, with `up` pointing upwards.
+    pub fn new(position: Vector3, forward: Vector3, up: Vector3) -> Self {
+        Listener { position, right: forward.cross(up).normalize() }
+    }
+
+    /// Creates a listener from the point of view of a 3d camera.
+    pub fn from_camera_3d(camera: &Camera3D) -> Self {
+        Listener::new(camera.position, camera.target - camera.position, camera.up)
+    }
+
+    /// Creates a listener at the target of a 2d camera (the center of the screen for a centered camera).
+    /// The right of the screen is the right of the listener, taking the camera's rotation into account.
+    pub fn from_camera_2d(camera: &Camera2D) -> Self {
+        let rotation = camera.rotation.to_radians();
+        Listener { position: camera.target.vec3(0.0), right: vec3(rotation.cos(), -rotation.sin(), 0.0) }
+    }
+
+    /// Creates a listener at a 2d position, with the right being `+x`.
+    pub fn from_position_2d(position: Vector2) -> Self {
+        Listener { position: position.vec3(0.0), right: vec3(1.0, 0.0, 0.0) }
+    }
+}
+
+/// How the volume of a sound decreases with distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistanceModel {
+    /// Decreases linearly, reaching silence at the max distance.
+    Linear,
+    /// Decreases with the inverse of the distance (physically accurate for `rolloff = 1.0`).
+    #[default]
+    Inverse,
+    /// Decreases with a power of the distance.
+    Exponential
+}
+
+/// Parameters of the distance attenuation, following the OpenAL (clamped) distance models.
+///
+/// Sounds closer than `reference_distance` play at full volume.
+/// Past `max_distance`, the volume stops decreasing (or is silent for [`DistanceModel::Linear`]).
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let attenuation = Attenuation::linear(1.0, 11.0);
+/// assert_eq!(attenuation.gain(0.5), 1.0);
+/// assert_eq!(attenuation.gain(6.0), 0.5);
+/// assert_eq!(attenuation.gain(20.0), 0.0);
+///
+/// let attenuation = Attenuation::inverse(1.0, 100.0, 1.0);
+/// assert_eq!(attenuation.gain(4.0), 0.25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    pub model: DistanceModel,
+    pub reference_distance: f32,
+    pub max_distance: f32,
+    /// How fast the volume decreases (1.0 is the model's natural rate).
+    pub rolloff: f32
+}
+
+impl Default for Attenuation {
+    fn default() -> Self {
+        Attenuation::inverse(1.0, 100.0, 1.0)
+    }
+}
+
+impl Attenuation {
+    pub fn new(model: DistanceModel, reference_distance: f32, max_distance: f32, rolloff: f32) -> Self {
+        Attenuation { model, reference_distance, max_distance, rolloff }
+    }
+    pub fn linear(reference_distance: f32, max_distance: f32) -> Self {
+        Attenuation::new(DistanceModel::Linear, reference_distance, max_distance, 1.0)
+    }
+    pub fn inverse(reference_distance: f32, max_distance: f32, rolloff: f32) -> Self {
+        Attenuation::new(DistanceModel::Inverse, reference_distance, max_distance, rolloff)
+    }
+    pub fn exponential(reference_distance: f32, max_distance: f32, rolloff: f32) -> Self {
+        Attenuation::new(DistanceModel::Exponential, reference_distance, max_distance, rolloff)
+    }
+
+    /// Volume multiplier for a sound at the given distance, between 0.0 and 1.0.
+    pub fn gain(&self, distance: f32) -> f32 {
+        let reference = self.reference_distance.max(f32::EPSILON);
+        let max = self.max_distance.max(reference);
+        let distance = distance.clamp(reference, max);
+
+        let gain = match self.model {
+            DistanceModel::Linear => {
+                if max == reference { return 1.0 }
+                1.0 - self.rolloff * (distance - reference) / (max - reference)
+            }
+            DistanceModel::Inverse => reference / (reference + self.rolloff * (distance - reference)),
+            DistanceModel::Exponential => (distance / reference).powf(-self.rolloff)
+        };
+        gain.clamp(0.0, 1.0)
+    }
+}
+
+/// Computes the volume and pan of a sound emitted at `emitter`, as heard by `listener`.
+///
+/// Returns `(volume, pan)`, with the pan following raylib's convention (0.5 is the center, 1.0 is fully left and 0.0 fully right).
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let listener = Listener::from_position_2d(vec2(0.0, 0.0));
+/// let (volume, pan) = spatialize(&listener, vec3(2.0, 0.0, 0.0), &Attenuation::linear(1.0, 3.0));
+/// assert_eq!(volume, 0.5);
+/// assert_eq!(pan, 0.0); // fully right
+/// ```
+pub fn spatialize(listener: &Listener, emitter: Vector3, attenuation: &Attenuation) -> (f32, f32) {
+    let offset = emitter - listener.position;
+    let distance = offset.length();
+    let volume = attenuation.gain(distance);
+
+    // a sound right on the listener is centered
+    if distance <= f32::EPSILON { return (volume, 0.5) }
+    let side = (offset / distance).dot(listener.right).clamp(-1.0, 1.0);
+    (volume, 0.5 - side * 0.5)
+}
+
+/// A [`Sound`] emitted from a position in the world.
+///
+/// [`SpatialSound::update`] should be called every frame (or when the listener or the emitter moves),
+/// to set the volume and pan of the sound.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut init_window(800, 800, "Spatial", 60);
+/// let audio = init_audio_device(rl).unwrap();
+/// let sound = Sound::load(&audio, "assets/coin.wav").unwrap();
+/// let mut coin = SpatialSound::new(sound, vec3(300.0, 400.0, 0.0), Attenuation::linear(50.0, 600.0));
+/// let camera = Camera2D { offset: vec2(400.0, 400.0), target: vec2(400.0, 400.0), rotation: 0.0, zoom: 1.0 };
+/// coin.play();
+/// while !window_should_close(rl) {
+///     coin.update(&Listener::from_camera_2d(&camera));
+///     begin_drawing(rl, |rl| clear_background(rl, Color::RAYWHITE));
+///     # break
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SpatialSound<'a> {
+    sound: Sound<'a>,
+    pub position: Vector3,
+    pub attenuation: Attenuation,
+    /// Volume of the sound at full gain (1.0 is the max level).
+    pub volume: f32
+}
+
+impl<'a> SpatialSound<'a> {
+    pub fn new(sound: Sound<'a>, position: Vector3, attenuation: Attenuation) -> Self {
+        SpatialSound { sound, position, attenuation, volume: 1.0 }
+    }
+
+    /// The sound being spatialized.
+    pub fn sound(&self) -> &Sound<'a> {
+        &self.sound
+    }
+    pub fn into_sound(self) -> Sound<'a> {
+        self.sound
+    }
+}
+
+impl SpatialSound<'_> {
+    /// Sets the volume and pan of the sound from the point of view of `listener`.
+    pub fn update(&self, listener: &Listener) {
+        let (volume, pan) = spatialize(listener, self.position, &self.attenuation);
+        self.sound.set_volume(volume * self.volume);
+        self.sound.set_pan(pan);
+    }
+
+    /// Plays the sound from the start.
+    /// Call [`SpatialSound::update`] before, so that it doesn't start at the wrong volume.
+    pub fn play(&self) {
+        self.sound.play()
+    }
+    pub fn stop(&self) {
+        self.sound.stop()
+    }
+    pub fn is_playing(&self) -> bool {
+        self.sound.is_playing()
+    }
+}
@@ -0,0 +1,236 @@
+use std::{f32::consts::TAU, sync::{Arc, Mutex, MutexGuard}};
+
+use super::processor::AudioProcessor;
+
+/// Number of previous flux values used for the adaptive onset threshold.
+const FLUX_HISTORY: usize = 43;
+
+/// Result of [`SpectrumAnalyzer::analyze`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Magnitude of each frequency bin, from 0 Hz to the Nyquist frequency (`size / 2 + 1` bins).
+    /// Use [`SpectrumAnalyzer::bin_frequency`] to get the frequency of a bin.
+    pub spectrum: Vec<f32>,
+    /// Root mean square level of the analyzed samples.
+    pub rms: f32,
+    /// Spectral flux: how much the spectrum's energy increased since the previous analysis.
+    pub flux: f32,
+    /// Wether a new sound (beat, note, ...) started since the previous analysis.
+    pub onset: bool
+}
+
+/// Analyzes the most recent samples going through it.
+///
+/// Attach its [`input`](SpectrumAnalyzer::input) to the device with [`AudioDevice::attach_mixed_processor`](super::AudioDevice::attach_mixed_processor)
+/// to analyze everything being played, then call [`SpectrumAnalyzer::analyze`] once per frame.
+/// Samples are downmixed to mono and left unchanged.
+/// The audio thread only waits while the samples are copied, the transform runs on the calling thread.
+///
+/// It can also analyze any buffer offline, with [`SpectrumAnalyzer::push_samples`].
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut analyzer = SpectrumAnalyzer::new(1024, 44100);
+/// let sine: Vec<f32> = (0..1024).map(|i| (i as f32 * std::f32::consts::TAU * 1000.0 / 44100.0).sin()).collect();
+/// analyzer.push_samples(&sine, 1);
+///
+/// let analysis = analyzer.analyze();
+/// let peak = (0..analysis.spectrum.len()).max_by(|&a, &b| analysis.spectrum[a].total_cmp(&analysis.spectrum[b])).unwrap();
+/// assert!((analyzer.bin_frequency(peak) - 1000.0).abs() < 44100.0 / 1024.0);
+/// assert!((analysis.rms - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+/// ```
+#[derive(Debug)]
+pub struct SpectrumAnalyzer {
+    sample_rate: u32,
+    size: usize,
+    /// Shared with the [`SpectrumInput`]s.
+    history: Arc<Mutex<History>>,
+    /// Hann window applied before the transform.
+    window: Vec<f32>,
+    previous_spectrum: Vec<f32>,
+    flux_history: Vec<f32>,
+    /// Onset threshold, in standard deviations above the average flux.
+    sensitivity: f32
+}
+
+impl SpectrumAnalyzer {
+    /// Creates an analyzer looking at the last `size` samples.
+    /// Larger sizes give a finer frequency resolution (`sample_rate / size` Hz per bin), but react slower.
+    ///
+    /// # Panics
+    /// Panics if `size` is not a power of two, or is smaller than 2.
+    pub fn new(size: usize, sample_rate: u32) -> Self {
+        assert!(size >= 2 && size.is_power_of_two(), "the analyzer size must be a power of two");
+        let window = (0..size).map(|i| 0.5 - 0.5 * (TAU * i as f32 / size as f32).cos()).collect();
+
+        SpectrumAnalyzer {
+            sample_rate,
+            size,
+            history: Arc::new(Mutex::new(History { samples: vec![0.0; size], position: 0 })),
+            window,
+            previous_spectrum: vec![0.0; size / 2 + 1],
+            flux_history: Vec::with_capacity(FLUX_HISTORY),
+            sensitivity: 1.5
+        }
+    }
+
+    /// Number of samples analyzed.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    /// Frequency at the center of a bin of the spectrum.
+    pub fn bin_frequency(&self, bin: usize) -> f32 {
+        bin as f32 * self.sample_rate as f32 / self.size() as f32
+    }
+
+    /// Sets how far above the average flux a peak needs to be to count as an onset,
+    /// in standard deviations (1.5 by default, lower detects more onsets).
+    pub fn set_sensitivity(&mut self, sensitivity: f32) {
+        self.sensitivity = sensitivity;
+    }
+
+    /// Gives a processor feeding the samples going through it to this analyzer.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use raylib::prelude::*;
+    /// let audio = AudioDevice::init().unwrap();
+    /// let mut analyzer = SpectrumAnalyzer::new(1024, audio.sample_rate());
+    /// let _input = audio.attach_mixed_processor(analyzer.input()).unwrap();
+    ///
+    /// // once per frame
+    /// let analysis = analyzer.analyze();
+    /// ```
+    pub fn input(&self) -> SpectrumInput {
+        SpectrumInput { history: self.history.clone() }
+    }
+
+    /// Adds interleaved samples to the analyzed history (downmixed to mono).
+    pub fn push_samples(&mut self, samples: &[f32], channels: usize) {
+        self.history().push(samples, channels)
+    }
+
+    /// Clears the history.
+    pub fn reset(&mut self) {
+        let mut history = self.history();
+        history.samples.iter_mut().for_each(|sample| *sample = 0.0);
+        history.position = 0;
+        drop(history);
+
+        self.previous_spectrum.iter_mut().for_each(|bin| *bin = 0.0);
+        self.flux_history.clear();
+    }
+
+    fn history(&self) -> MutexGuard<'_, History> {
+        self.history.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Analyzes the most recent samples.
+    /// Onset detection compares with the previous call, so this should be called at a regular interval (like once per frame).
+    pub fn analyze(&mut self) -> Analysis {
+        let size = self.size;
+
+        // copied so that the audio thread isn't blocked during the transform, oldest sample first
+        let samples: Vec<f32> = {
+            let history = self.history();
+            history.samples[history.position..].iter().chain(&history.samples[..history.position]).copied().collect()
+        };
+        let rms = (samples.iter().map(|sample| sample * sample).sum::<f32>() / size as f32).sqrt();
+
+        let mut real: Vec<f32> = samples.iter().zip(&self.window).map(|(sample, window)| sample * window).collect();
+        let mut imaginary = vec![0.0; size];
+        fft(&mut real, &mut imaginary);
+
+        // normalized so that a full scale sine gives a magnitude of 1.0 (the hann window halves the amplitude)
+        let scale = 4.0 / size as f32;
+        let spectrum: Vec<f32> = real.iter().zip(&imaginary).take(size / 2 + 1)
+            .map(|(re, im)| (re * re + im * im).sqrt() * scale)
+            .collect();
+
+        let flux = spectrum.iter().zip(&self.previous_spectrum).map(|(current, previous)| (current - previous).max(0.0)).sum();
+        let onset = self.detect_onset(flux);
+        self.previous_spectrum.copy_from_slice(&spectrum);
+
+        Analysis { spectrum, rms, flux, onset }
+    }
+
+    fn detect_onset(&mut self, flux: f32) -> bool {
+        let onset = if self.flux_history.len() < 2 { false } else {
+            let count = self.flux_history.len() as f32;
+            let mean = self.flux_history.iter().sum::<f32>() / count;
+            let variance = self.flux_history.iter().map(|value| (value - mean).powi(2)).sum::<f32>() / count;
+            flux > mean + self.sensitivity * variance.sqrt() && flux > f32::EPSILON
+        };
+
+        if self.flux_history.len() == FLUX_HISTORY { self.flux_history.remove(0); }
+        self.flux_history.push(flux);
+        onset
+    }
+}
+
+/// Ring buffer of the most recent mono samples.
+#[derive(Debug)]
+struct History {
+    samples: Vec<f32>,
+    position: usize
+}
+
+impl History {
+    fn push(&mut self, samples: &[f32], channels: usize) {
+        let size = self.samples.len();
+        for frame in samples.chunks_exact(channels) {
+            self.samples[self.position] = frame.iter().sum::<f32>() / channels as f32;
+            self.position = (self.position + 1) % size;
+        }
+    }
+}
+
+/// Processor feeding a [`SpectrumAnalyzer`], created with [`SpectrumAnalyzer::input`].
+#[derive(Debug, Clone)]
+pub struct SpectrumInput {
+    history: Arc<Mutex<History>>
+}
+
+impl AudioProcessor for SpectrumInput {
+    fn process(&mut self, samples: &mut [f32], channels: usize) {
+        if let Ok(mut history) = self.history.lock() {
+            history.push(samples, channels)
+        }
+    }
+}
+
+/// In-place iterative radix-2 fast fourier transform.
+/// Both slices must have the same power of two length.
+fn fft(real: &mut [f32], imaginary: &mut [f32]) {
+    let n = real.len();
+    debug_assert!(n.is_power_of_two() && imaginary.len() == n);
+
+    // bit reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            real.swap(i, j);
+            imaginary.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+    while length <= n {
+        let angle = -TAU / length as f32;
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + length / 2);
+
+                let re = real[b] * cos - imaginary[b] * sin;
+                let im = real[b] * sin + imaginary[b] * cos;
+                real[b] = real[a] - re;
+                imaginary[b] = imaginary[a] - im;
+                real[a] += re;
+                imaginary[a] += im;
+            }
+        }
+        length *= 2;
+    }
+}
@@ -0,0 +1,200 @@
+use std::{ffi::c_void, marker::PhantomData};
+
+use crate::ffi;
+
+use super::{callback::{self, Owner}, wave::Sample, AudioDevice, AudioError};
+
+#[allow(clashing_extern_declarations)]
+extern "C" {
+    // Same function as `ffi::SetAudioStreamCallback`, but allows passing NULL to remove the callback.
+    #[link_name = "SetAudioStreamCallback"]
+    fn SetAudioStreamCallbackNullable(stream: ffi::AudioStream, callback: Option<ffi::AudioCallback>);
+}
+
+/// A custom audio stream, to play audio generated on the fly.
+/// Use [`AudioStream::load`] to create one.
+///
+/// Data can be given to the stream in two ways:
+/// - by pushing samples with [`AudioStream::update`] whenever [`AudioStream::is_processed`] returns `true`,
+/// - by setting a callback with [`AudioStream::set_callback`], which gets called from the audio thread when new data is needed.
+///
+/// A stream borrows the [`AudioDevice`] it was loaded with, and is unloaded when dropped.
+#[derive(Debug)]
+pub struct AudioStream<'a>(ffi::AudioStream, PhantomData<&'a AudioDevice>);
+
+impl Drop for AudioStream<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::UnloadAudioStream(self.0) }
+        // the buffer was removed from the mixer, the callback can't be called anymore
+        if let Some(slot) = callback::find(Owner::StreamCallback(self.0.buffer as usize)) {
+            callback::release(slot)
+        }
+    }
+}
+
+impl ffi::AudioStream {
+    /// Checks if the audio stream metadata is valid.
+    /// Equivalent to `IsAudioStreamReady`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::IsAudioStreamReady(*self) }
+    }
+}
+
+impl<'a> AudioStream<'a> {
+    /// Creates an audio stream with the given format.
+    ///
+    /// Streams using a callback should use a sample size of 32 bits.
+    /// # Panics
+    /// Panics if `sample_size` is not 8, 16 or 32, or if `sample_rate` or `channels` are 0.
+    pub fn load(_audio: &'a AudioDevice, sample_rate: u32, sample_size: u32, channels: u32) -> Result<AudioStream<'a>, AudioError> {
+        assert!(matches!(sample_size, 8 | 16 | 32), "the sample size must be 8, 16 or 32 bits");
+        assert!(sample_rate > 0 && channels > 0);
+
+        let stream = unsafe { ffi::LoadAudioStream(sample_rate, sample_size, channels) };
+        if !stream.is_valid() { return Err(AudioError::InvalidData) }
+        Ok(AudioStream(stream, PhantomData))
+    }
+}
+
+impl AudioStream<'_> {
+    /// Frequency (frames per second).
+    #[inline]
+    pub fn sample_rate(&self) -> u32 {
+        self.0.sampleRate
+    }
+    /// Bit depth (bits per sample): 8, 16 or 32.
+    #[inline]
+    pub fn sample_size(&self) -> u32 {
+        self.0.sampleSize
+    }
+    /// Number of channels (1 for mono, 2 for stereo, ...).
+    #[inline]
+    pub fn channels(&self) -> u32 {
+        self.0.channels
+    }
+
+    /// Pushes interleaved samples to the stream.
+    /// This should only be done when [`AudioStream::is_processed`] returns `true`.
+    ///
+    /// # Panics
+    /// Panics if `T` does not correspond to the sample size of the stream,
+    /// or if the number of samples is not a multiple of the number of channels.
+    pub fn update<T: Sample>(&self, samples: &[T]) {
+        assert_eq!(T::BITS, self.0.sampleSize, "the sample type must match the stream's sample size");
+        let channels = self.0.channels as usize;
+        assert!(samples.len().is_multiple_of(channels), "the sample count must be a multiple of the channel count");
+
+        unsafe { ffi::UpdateAudioStream(self.0, samples.as_ptr() as *const c_void, (samples.len() / channels) as i32) }
+    }
+    /// Checks if one of the stream buffers was played and needs new data.
+    #[inline]
+    pub fn is_processed(&self) -> bool {
+        unsafe { ffi::IsAudioStreamProcessed(self.0) }
+    }
+
+    /// Sets a callback generating the stream's data.
+    ///
+    /// The callback is called from the audio thread with a buffer of interleaved samples to fill.
+    /// It should return quickly, as the whole mixer waits for it.
+    /// Setting a new callback replaces the previous one.
+    /// A callback that panics is removed, and the stream is left silent.
+    ///
+    /// Returns [`AudioError::TooManyCallbacks`] if there are already [`MAX_AUDIO_CALLBACKS`](super::MAX_AUDIO_CALLBACKS)
+    /// callbacks and processors registered.
+    ///
+    /// # Panics
+    /// Panics if the sample size of the stream is not 32 bits.
+    /// # Examples
+    /// Generate a sine wave:
+    /// ```
+    /// # use raylib::prelude::*;
+    /// # let rl = &mut init_window(100, 100, "stream", 60);
+    /// let audio = init_audio_device(rl).unwrap();
+    /// let mut stream = AudioStream::load(&audio, 44100, 32, 1).unwrap();
+    /// let mut phase = 0.0f32;
+    /// stream.set_callback(move |samples| {
+    ///     for sample in samples {
+    ///         *sample = (phase * std::f32::consts::TAU).sin() * 0.2;
+    ///         phase = (phase + 440.0 / 44100.0).fract();
+    ///     }
+    /// }).unwrap();
+    /// stream.play();
+    /// ```
+    pub fn set_callback(&mut self, mut callback: impl FnMut(&mut [f32]) + Send + 'static) -> Result<(), AudioError> {
+        assert_eq!(self.0.sampleSize, 32, "stream callbacks need a 32 bit sample size");
+
+        let channels = self.0.channels as usize;
+        let raw: callback::RawCallback = Box::new(move |data, frames| {
+            // SAFETY: raylib gives a buffer of `frames` frames in the stream's format (32 bit floats)
+            let samples = unsafe { std::slice::from_raw_parts_mut(data as *mut f32, frames as usize * channels) };
+            callback(samples)
+        });
+
+        let owner = Owner::StreamCallback(self.0.buffer as usize);
+        match callback::find(owner) {
+            Some(slot) => callback::replace(slot, raw),
+            None => {
+                let slot = callback::register(owner, raw)?;
+                unsafe { ffi::SetAudioStreamCallback(self.0, slot.trampoline()) }
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the callback set with [`AudioStream::set_callback`].
+    /// The stream goes back to playing the data given by [`AudioStream::update`].
+    pub fn clear_callback(&mut self) {
+        if let Some(slot) = callback::find(Owner::StreamCallback(self.0.buffer as usize)) {
+            unsafe { SetAudioStreamCallbackNullable(self.0, None) }
+            callback::release(slot)
+        }
+    }
+
+    /// Starts playing the stream.
+    #[inline]
+    pub fn play(&self) {
+        unsafe { ffi::PlayAudioStream(self.0) }
+    }
+    /// Pauses the stream.
+    #[inline]
+    pub fn pause(&self) {
+        unsafe { ffi::PauseAudioStream(self.0) }
+    }
+    /// Resumes a paused stream.
+    #[inline]
+    pub fn resume(&self) {
+        unsafe { ffi::ResumeAudioStream(self.0) }
+    }
+    /// Checks if the stream is playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsAudioStreamPlaying(self.0) }
+    }
+    /// Stops the stream.
+    #[inline]
+    pub fn stop(&self) {
+        unsafe { ffi::StopAudioStream(self.0) }
+    }
+    /// Sets the volume of the stream (1.0 is the max level).
+    #[inline]
+    pub fn set_volume(&self, volume: f32) {
+        unsafe { ffi::SetAudioStreamVolume(self.0, volume) }
+    }
+    /// Sets the pitch of the stream (1.0 is the base level).
+    #[inline]
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe { ffi::SetAudioStreamPitch(self.0, pitch) }
+    }
+    /// Sets the pan of the stream (0.5 is the center, 1.0 is fully left and 0.0 fully right).
+    #[inline]
+    pub fn set_pan(&self, pan: f32) {
+        unsafe { ffi::SetAudioStreamPan(self.0, pan) }
+    }
+
+    /// Get a reference to the underlying ffi audio stream
+    #[inline]
+    pub fn get_ffi(&self) -> &ffi::AudioStream {
+        &self.0
+    }
+}
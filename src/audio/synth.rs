@@ -0,0 +1,507 @@
+//! Procedural sound generation.
+//!
+//! Builds samples out of oscillators, envelopes and pitch sweeps, with [`Tone`] as the basic building block.
+//! [`SfxParams`] is an sfxr-style generator, with presets for common game sound effects.
+//!
+//! Generation is deterministic: the same parameters (and seed) always give the same samples.
+//! # Example
+//! ```
+//! # use raylib::prelude::*;
+//! # let rl = &mut init_window(100, 100, "synth", 60);
+//! let audio = init_audio_device(rl).unwrap();
+//! let coin = Sound::load_from_wave(&audio, &SfxParams::coin(42).to_wave(44100)).unwrap();
+//! coin.play();
+//! ```
+
+use std::f32::consts::TAU;
+
+use super::{effects::Biquad, processor::AudioProcessor, Wave};
+
+/// A small deterministic random number generator (xorshift64*).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // splitmix64, so that close seeds (and 0) give unrelated states
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Random value in `[0, 1)`.
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Random value in `[min, max)`.
+    pub(crate) fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+/// Shape of an oscillator's signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Waveform {
+    #[default]
+    Sine,
+    /// Pulse wave, with a configurable duty cycle.
+    Square,
+    Saw,
+    Triangle,
+    /// White noise, changing value twice per period (so the frequency still affects its color).
+    Noise
+}
+
+/// A periodic signal generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Oscillator {
+    pub waveform: Waveform,
+    /// Fraction of the period spent high for [`Waveform::Square`], between 0.0 and 1.0.
+    pub duty: f32,
+    phase: f32,
+    noise: f32,
+    rng: Rng
+}
+
+impl Oscillator {
+    /// Creates an oscillator with a 50% duty cycle.
+    /// The seed is only used by [`Waveform::Noise`].
+    pub fn new(waveform: Waveform, seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        let noise = rng.range(-1.0, 1.0);
+        Oscillator { waveform, duty: 0.5, phase: 0.0, noise, rng }
+    }
+
+    /// Current position in the period, between 0.0 and 1.0.
+    pub fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// Returns the current sample (between -1.0 and 1.0), and advances by one sample at the given frequency.
+    pub fn next_sample(&mut self, frequency: f32, sample_rate: f32) -> f32 {
+        let phase = self.phase;
+        let value = match self.waveform {
+            Waveform::Sine => (phase * TAU).sin(),
+            Waveform::Square => if phase < self.duty { 1.0 } else { -1.0 },
+            Waveform::Saw => 2.0 * phase - 1.0,
+            Waveform::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+            Waveform::Noise => self.noise
+        };
+
+        self.phase += frequency / sample_rate;
+        let crossed_half = phase < 0.5 && self.phase >= 0.5;
+        if self.phase >= 1.0 { self.phase = self.phase.fract() }
+
+        if self.waveform == Waveform::Noise && (crossed_half || self.phase < phase) {
+            self.noise = self.rng.range(-1.0, 1.0);
+        }
+
+        value
+    }
+}
+
+/// Attack, decay, sustain, release envelope (durations in seconds).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adsr {
+    /// Time to go from silence to full volume.
+    pub attack: f32,
+    /// Time to go from full volume to the sustain level.
+    pub decay: f32,
+    /// Level held until the note is released, between 0.0 and 1.0.
+    pub sustain: f32,
+    /// Time to go back to silence after the note is released.
+    pub release: f32
+}
+
+impl Default for Adsr {
+    fn default() -> Self {
+        Adsr { attack: 0.01, decay: 0.1, sustain: 0.7, release: 0.1 }
+    }
+}
+
+impl Adsr {
+    pub fn new(attack: f32, decay: f32, sustain: f32, release: f32) -> Self {
+        Adsr { attack, decay, sustain, release }
+    }
+
+    /// Level of the envelope at `time`, for a note released after `note_length` seconds.
+    pub fn level(&self, time: f32, note_length: f32) -> f32 {
+        if time < 0.0 { return 0.0 }
+        if time < note_length { return self.held_level(time) }
+
+        if self.release <= 0.0 { return 0.0 }
+        let released = (time - note_length) / self.release;
+        self.held_level(note_length) * (1.0 - released).max(0.0)
+    }
+
+    /// Total duration of a note released after `note_length` seconds.
+    pub fn duration(&self, note_length: f32) -> f32 {
+        note_length + self.release
+    }
+
+    fn held_level(&self, time: f32) -> f32 {
+        if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (time - self.attack) / self.decay
+        } else {
+            self.sustain
+        }
+    }
+}
+
+/// How a [`Sweep`] goes from its start to its end value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SweepCurve {
+    #[default]
+    Linear,
+    /// Constant ratio over time, which sounds linear for pitches.
+    Exponential
+}
+
+/// A frequency changing over the duration of a sound.
+///
+/// [`SweepCurve::Exponential`] sweeps need positive values, smaller values are treated as [`Sweep::MIN_EXPONENTIAL`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sweep {
+    pub start: f32,
+    pub end: f32,
+    pub curve: SweepCurve
+}
+
+impl Sweep {
+    /// Smallest value of an exponential sweep (a ratio can't start or end at 0).
+    pub const MIN_EXPONENTIAL: f32 = 0.001;
+
+    pub fn new(start: f32, end: f32, curve: SweepCurve) -> Self {
+        Sweep { start, end, curve }
+    }
+    /// A frequency that doesn't change.
+    pub fn constant(frequency: f32) -> Self {
+        Sweep::new(frequency, frequency, SweepCurve::Linear)
+    }
+
+    /// Value of the sweep at `progress`, between 0.0 (start) and 1.0 (end).
+    /// # Example
+    /// ```
+    /// # use raylib::prelude::*;
+    /// let sweep = Sweep::new(0.0, 440.0, SweepCurve::Exponential);
+    /// assert_eq!(sweep.value(0.0), Sweep::MIN_EXPONENTIAL);
+    /// assert!(sweep.value(0.5).is_finite());
+    /// ```
+    pub fn value(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self.curve {
+            SweepCurve::Linear => self.start + (self.end - self.start) * progress,
+            SweepCurve::Exponential => {
+                let start = self.start.max(Sweep::MIN_EXPONENTIAL);
+                let end = self.end.max(Sweep::MIN_EXPONENTIAL);
+                start * (end / start).powf(progress)
+            }
+        }
+    }
+}
+
+/// A single note: an oscillator shaped by an envelope, with a pitch sweep.
+/// # Example
+/// A one second A4 note, mixed with its octave:
+/// ```
+/// # use raylib::prelude::*;
+/// let mut note = Tone::new(Waveform::Triangle, 440.0, 0.8);
+/// let low = note.render(44100);
+/// note.frequency = Sweep::constant(880.0);
+/// note.volume = 0.25;
+/// let high = note.render(44100);
+///
+/// let samples = mix_samples(&[&low, &high]);
+/// let wave = Wave::from_samples(44100, 1, &samples);
+/// assert_eq!(wave.frame_count() as usize, low.len());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tone {
+    pub waveform: Waveform,
+    /// Duty cycle for [`Waveform::Square`].
+    pub duty: f32,
+    pub frequency: Sweep,
+    pub envelope: Adsr,
+    /// Time before the note is released (in seconds), the sound lasts for `envelope.release` more.
+    pub note_length: f32,
+    pub volume: f32,
+    /// Seed used by [`Waveform::Noise`].
+    pub seed: u64
+}
+
+impl Tone {
+    /// Creates a tone with the default envelope, at half volume.
+    pub fn new(waveform: Waveform, frequency: f32, note_length: f32) -> Self {
+        Tone {
+            waveform,
+            duty: 0.5,
+            frequency: Sweep::constant(frequency),
+            envelope: Adsr::default(),
+            note_length,
+            volume: 0.5,
+            seed: 0
+        }
+    }
+
+    /// Generates the samples of the tone (mono).
+    pub fn render(&self, sample_rate: u32) -> Vec<f32> {
+        let rate = sample_rate as f32;
+        let duration = self.envelope.duration(self.note_length);
+        let count = (duration * rate).ceil() as usize;
+
+        let mut oscillator = Oscillator::new(self.waveform, self.seed);
+        oscillator.duty = self.duty;
+
+        (0..count).map(|i| {
+            let time = i as f32 / rate;
+            let frequency = self.frequency.value(time / duration);
+            oscillator.next_sample(frequency, rate) * self.envelope.level(time, self.note_length) * self.volume
+        }).collect()
+    }
+
+    /// Generates a mono wave from the tone (32 bit samples).
+    pub fn to_wave(&self, sample_rate: u32) -> Wave {
+        samples_to_wave(sample_rate, &self.render(sample_rate))
+    }
+}
+
+/// Mixes several sample buffers together by adding them.
+/// The result is as long as the longest buffer, and is clamped between -1.0 and 1.0.
+pub fn mix_samples(layers: &[&[f32]]) -> Vec<f32> {
+    let length = layers.iter().map(|layer| layer.len()).max().unwrap_or(0);
+    let mut mixed = vec![0.0; length];
+    for layer in layers {
+        mixed.iter_mut().zip(layer.iter()).for_each(|(mixed, sample)| *mixed += sample);
+    }
+    mixed.iter_mut().for_each(|sample| *sample = sample.clamp(-1.0, 1.0));
+    mixed
+}
+
+fn samples_to_wave(sample_rate: u32, samples: &[f32]) -> Wave {
+    // a wave can't be empty
+    if samples.is_empty() { return Wave::from_samples(sample_rate, 1, &[0.0f32]) }
+    Wave::from_samples(sample_rate, 1, samples)
+}
+
+/// Parameters of an sfxr-style sound effect.
+///
+/// Use a preset ([`SfxParams::coin`], [`SfxParams::jump`], [`SfxParams::explosion`], [`SfxParams::laser`])
+/// as a starting point, and tweak the fields from there.
+/// Durations are in seconds, and frequencies in Hz.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SfxParams {
+    pub waveform: Waveform,
+    /// Duty cycle for [`Waveform::Square`].
+    pub duty: f32,
+    /// Change of the duty cycle per second.
+    pub duty_sweep: f32,
+
+    /// Starting frequency.
+    pub frequency: f32,
+    /// The sound stops early if its frequency slides below this.
+    pub min_frequency: f32,
+    /// Frequency slide, in octaves per second.
+    pub slide: f32,
+    /// Change of the frequency slide, in octaves per second squared.
+    pub delta_slide: f32,
+
+    /// Vibrato depth, as a fraction of the frequency.
+    pub vibrato_depth: f32,
+    pub vibrato_speed: f32,
+
+    /// Frequency multiplier applied once after `arpeggio_time` (1.0 to disable).
+    pub arpeggio_multiplier: f32,
+    pub arpeggio_time: f32,
+
+    pub attack: f32,
+    pub sustain: f32,
+    /// Extra volume at the start of the sustain, fading during it.
+    pub punch: f32,
+    pub decay: f32,
+
+    /// Cutoff of a low-pass filter applied to the sound.
+    pub low_pass: Option<f32>,
+    pub volume: f32,
+    /// Seed used by [`Waveform::Noise`].
+    pub seed: u64
+}
+
+impl Default for SfxParams {
+    /// A short square beep.
+    fn default() -> Self {
+        SfxParams {
+            waveform: Waveform::Square,
+            duty: 0.5,
+            duty_sweep: 0.0,
+            frequency: 440.0,
+            min_frequency: 0.0,
+            slide: 0.0,
+            delta_slide: 0.0,
+            vibrato_depth: 0.0,
+            vibrato_speed: 0.0,
+            arpeggio_multiplier: 1.0,
+            arpeggio_time: 0.0,
+            attack: 0.0,
+            sustain: 0.1,
+            punch: 0.0,
+            decay: 0.1,
+            low_pass: None,
+            volume: 0.5,
+            seed: 0
+        }
+    }
+}
+
+/// # Presets
+///
+/// Every preset is randomized from the given seed, so different seeds give variations of the same kind of sound.
+///
+/// ---
+impl SfxParams {
+    /// A pickup sound: a short square note, jumping up in pitch.
+    pub fn coin(seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        SfxParams {
+            frequency: rng.range(700.0, 1400.0),
+            arpeggio_multiplier: rng.range(1.3, 1.6),
+            arpeggio_time: rng.range(0.04, 0.09),
+            sustain: rng.range(0.04, 0.1),
+            punch: rng.range(0.3, 0.6),
+            decay: rng.range(0.1, 0.3),
+            volume: 0.4,
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// A jump sound: a square note sliding up.
+    pub fn jump(seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        SfxParams {
+            duty: rng.range(0.2, 0.6),
+            frequency: rng.range(250.0, 500.0),
+            slide: rng.range(1.5, 3.0),
+            sustain: rng.range(0.05, 0.15),
+            decay: rng.range(0.1, 0.25),
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// An explosion: low, filtered noise sliding down.
+    pub fn explosion(seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        SfxParams {
+            waveform: Waveform::Noise,
+            frequency: rng.range(1500.0, 4000.0),
+            slide: rng.range(-2.0, -0.5),
+            vibrato_depth: rng.range(0.0, 0.3),
+            vibrato_speed: rng.range(5.0, 20.0),
+            sustain: rng.range(0.1, 0.3),
+            punch: rng.range(0.2, 0.6),
+            decay: rng.range(0.3, 0.7),
+            low_pass: Some(rng.range(800.0, 2500.0)),
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// A laser shot: a bright note sliding down quickly.
+    pub fn laser(seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        SfxParams {
+            waveform: if rng.next_f32() < 0.5 { Waveform::Square } else { Waveform::Saw },
+            duty: rng.range(0.2, 0.5),
+            duty_sweep: rng.range(0.0, 2.0),
+            frequency: rng.range(900.0, 1800.0),
+            min_frequency: rng.range(100.0, 300.0),
+            slide: rng.range(-6.0, -3.0),
+            sustain: rng.range(0.05, 0.15),
+            punch: rng.range(0.0, 0.3),
+            decay: rng.range(0.05, 0.2),
+            seed,
+            ..Default::default()
+        }
+    }
+}
+
+impl SfxParams {
+    /// Total duration of the sound, if its frequency doesn't slide below `min_frequency`.
+    pub fn duration(&self) -> f32 {
+        self.attack + self.sustain + self.decay
+    }
+
+    /// Generates the samples of the sound effect (mono).
+    /// # Example
+    /// The presets always generate the same samples (checked here against stored hashes):
+    /// ```
+    /// # use raylib::prelude::*;
+    /// // FNV-1a of the samples as 16 bit integers
+    /// fn hash(samples: &[f32]) -> u64 {
+    ///     samples.iter()
+    ///         .flat_map(|sample| ((sample * 32767.0).round() as i16).to_le_bytes())
+    ///         .fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+    /// }
+    ///
+    /// assert_eq!(hash(&SfxParams::coin(1).render(44100)), 0x56afea5eb0c051bd);
+    /// assert_eq!(hash(&SfxParams::jump(1).render(44100)), 0xcef435f617bba4c7);
+    /// assert_eq!(hash(&SfxParams::explosion(1).render(44100)), 0x3cc6ba78122090f2);
+    /// assert_eq!(hash(&SfxParams::laser(1).render(44100)), 0x9d932af2f5b8386b);
+    /// ```
+    pub fn render(&self, sample_rate: u32) -> Vec<f32> {
+        let rate = sample_rate as f32;
+        let count = (self.duration() * rate).ceil() as usize;
+
+        let mut oscillator = Oscillator::new(self.waveform, self.seed);
+        let mut samples = Vec::with_capacity(count);
+
+        for i in 0..count {
+            let time = i as f32 / rate;
+
+            let octaves = self.slide * time + 0.5 * self.delta_slide * time * time;
+            let mut frequency = self.frequency * octaves.exp2();
+            if self.arpeggio_multiplier != 1.0 && time >= self.arpeggio_time {
+                frequency *= self.arpeggio_multiplier;
+            }
+            if frequency < self.min_frequency { break }
+            frequency *= 1.0 + self.vibrato_depth * (TAU * self.vibrato_speed * time).sin();
+
+            oscillator.duty = (self.duty + self.duty_sweep * time).clamp(0.05, 0.95);
+            samples.push(oscillator.next_sample(frequency, rate) * self.envelope(time) * self.volume);
+        }
+
+        if let Some(cutoff) = self.low_pass {
+            Biquad::low_pass(rate, cutoff.min(rate * 0.49), std::f32::consts::FRAC_1_SQRT_2).process(&mut samples, 1);
+        }
+        samples.iter_mut().for_each(|sample| *sample = sample.clamp(-1.0, 1.0));
+        samples
+    }
+
+    /// Generates a mono wave from the sound effect (32 bit samples).
+    pub fn to_wave(&self, sample_rate: u32) -> Wave {
+        samples_to_wave(sample_rate, &self.render(sample_rate))
+    }
+
+    fn envelope(&self, time: f32) -> f32 {
+        if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.sustain {
+            let progress = (time - self.attack) / self.sustain;
+            1.0 + (1.0 - progress) * 2.0 * self.punch
+        } else {
+            let progress = (time - self.attack - self.sustain) / self.decay;
+            (1.0 - progress).max(0.0)
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use std::ffi::CString;
+
+use crate::ffi;
+
+use super::{AudioError, AudioFiletype};
+
+/// raylib's QOA encoder (`external/qoa.h`), compiled into raudio with the `qoa` feature.
+#[cfg(feature = "qoa")]
+mod qoa {
+    use std::ffi::{c_int, c_short, c_uint, c_void};
+
+    #[repr(C)]
+    pub struct QoaDesc {
+        pub channels: c_uint,
+        pub samplerate: c_uint,
+        pub samples: c_uint,
+        /// LMS history and weights of each channel, set by the encoder.
+        pub lms: [[c_int; 8]; 8]
+    }
+
+    extern "C" {
+        /// Returns a buffer allocated with `RL_MALLOC`, or null if the description is invalid.
+        pub fn qoa_encode(sample_data: *const c_short, qoa: *mut QoaDesc, out_len: *mut c_uint) -> *mut c_void;
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
+    impl Sealed for i16 {}
+    impl Sealed for f32 {}
+}
+
+/// A type that can be used to store wave samples.
+///
+/// Implemented for `u8` (8 bit, unsigned), `i16` (16 bit, signed) and `f32` (32 bit, floating point).
+pub trait Sample: private::Sealed + Copy + 'static {
+    /// The `sampleSize` of waves storing this type of sample.
+    const BITS: u32;
+    /// Converts the sample to a float in the `[-1.0; 1.0]` range.
+    fn to_f32(self) -> f32;
+    /// Converts a float in the `[-1.0; 1.0]` range to a sample, clamping it if needed.
+    fn from_f32(value: f32) -> Self;
+}
+
+impl Sample for u8 {
+    const BITS: u32 = 8;
+    fn to_f32(self) -> f32 { (self as f32 - 128.0)/128.0 }
+    fn from_f32(value: f32) -> Self { (value.clamp(-1.0, 1.0)*127.0 + 128.0) as u8 }
+}
+
+impl Sample for i16 {
+    const BITS: u32 = 16;
+    fn to_f32(self) -> f32 { self as f32/32767.0 }
+    fn from_f32(value: f32) -> Self { (value.clamp(-1.0, 1.0)*32767.0) as i16 }
+}
+
+impl Sample for f32 {
+    const BITS: u32 = 32;
+    fn to_f32(self) -> f32 { self }
+    fn from_f32(value: f32) -> Self { value }
+}
+
+/// Typed access to the samples of a [`Wave`], depending on its sample size.
+#[derive(Debug)]
+pub enum WaveData<'a> {
+    U8(&'a [u8]),
+    I16(&'a [i16]),
+    F32(&'a [f32])
+}
+
+/// Typed mutable access to the samples of a [`Wave`], depending on its sample size.
+#[derive(Debug)]
+pub enum WaveDataMut<'a> {
+    U8(&'a mut [u8]),
+    I16(&'a mut [i16]),
+    F32(&'a mut [f32])
+}
+
+/// Filetypes a [`Wave`] can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveExportFiletype {
+    /// RIFF wave file (requires the `wav` feature for file export).
+    Wav,
+    /// Quite OK Audio file, only for 16 bit waves (requires the `qoa` feature).
+    Qoa,
+    /// Raw interleaved samples, without any header.
+    Raw
+}
+
+/// Raw audio data.
+/// Use [`Wave::load`] to create one.
+///
+/// Waves are stored on the CPU in RAM, and don't need an [`AudioDevice`](super::AudioDevice) to be loaded or modified.
+/// To play a wave, turn it into a [`Sound`](super::Sound) with [`Sound::load_from_wave`](super::Sound::load_from_wave).
+///
+/// # Safety
+/// A `Wave` is garanteed to have valid metadata and a well allocated data buffer.
+#[derive(Debug)]
+pub struct Wave(ffi::Wave);
+
+impl Drop for Wave {
+    fn drop(&mut self) {
+        unsafe { ffi::UnloadWave(self.0) }
+    }
+}
+
+impl ffi::Wave {
+    /// Checks if the wave metadata is valid.
+    /// Equivalent to `IsWaveReady`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::IsWaveReady(*self) }
+    }
+}
+
+impl Wave {
+    /// Creates a safe wave struct from an ffi wave.
+    /// Checks for wave validity and returns `None` if it is not valid.
+    #[inline]
+    pub fn from_ffi(wave: ffi::Wave) -> Option<Self> {
+        if !wave.is_valid() { return None }
+        Some(Wave(wave))
+    }
+
+    /// Loads a wave from a file.
+    ///
+    /// Supports `wav`, `ogg`, `mp3`, `qoa` and `flac` files (if they were compiled into raylib).
+    /// Returns [`AudioError::UnsupportedFormat`] if support for the file's format was compiled out.
+    pub fn load(filename: impl AsRef<std::path::Path>) -> Result<Wave, AudioError> {
+        let filetype = AudioFiletype::from_path(&filename).ok_or(AudioError::UnknownFormat)?;
+        let data = crate::core::vfs::read(filename)?;
+        Wave::load_from_memory(filetype, &data)
+    }
+
+    /// Loads a wave from a memory buffer (in the given filetype's encoding).
+    pub fn load_from_memory(filetype: AudioFiletype, data: &[u8]) -> Result<Wave, AudioError> {
+        if !filetype.is_wave() { return Err(AudioError::UnknownFormat) }
+        filetype.check_supported()?;
+
+        let wave = unsafe { ffi::LoadWaveFromMemory(filetype.extension().as_ptr(), data.as_ptr(), data.len() as i32) };
+        Wave::from_ffi(wave).ok_or(AudioError::InvalidData)
+    }
+
+    /// Creates a wave from interleaved samples.
+    /// The sample size of the wave is deduced from the sample type.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty, if `channels` is 0, or if the number of samples is not a multiple of `channels`.
+    pub fn from_samples<T: Sample>(sample_rate: u32, channels: u32, samples: &[T]) -> Wave {
+        assert!(!samples.is_empty(), "a wave needs at least one sample");
+        assert!(channels > 0, "a wave needs at least one channel");
+        assert!(samples.len().is_multiple_of(channels as usize), "the sample count must be a multiple of the channel count");
+
+        let size = std::mem::size_of_val(samples);
+        let data = unsafe { ffi::MemAlloc(size as u32) };
+        assert!(!data.is_null(), "failed to allocate wave data");
+
+        // SAFETY: `data` was allocated with enough space for `samples`, and both buffers can't overlap.
+        unsafe { std::ptr::copy_nonoverlapping(samples.as_ptr() as *const u8, data as *mut u8, size) };
+
+        Wave(ffi::Wave {
+            frameCount: (samples.len() / channels as usize) as u32,
+            sampleRate: sample_rate,
+            sampleSize: T::BITS,
+            channels,
+            data
+        })
+    }
+
+    /// Total number of frames (a frame contains one sample per channel).
+    #[inline]
+    pub fn frame_count(&self) -> u32 {
+        self.0.frameCount
+    }
+
+    /// Frequency (frames per second).
+    #[inline]
+    pub fn sample_rate(&self) -> u32 {
+        self.0.sampleRate
+    }
+
+    /// Bit depth (bits per sample): 8, 16 or 32.
+    #[inline]
+    pub fn sample_size(&self) -> u32 {
+        self.0.sampleSize
+    }
+
+    /// Number of channels (1 for mono, 2 for stereo, ...).
+    #[inline]
+    pub fn channels(&self) -> u32 {
+        self.0.channels
+    }
+
+    /// Duration of the wave in seconds.
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        self.0.frameCount as f32 / self.0.sampleRate as f32
+    }
+
+    /// Total number of samples in the wave (frames times channels).
+    #[inline]
+    pub fn sample_count(&self) -> usize {
+        self.0.frameCount as usize * self.0.channels as usize
+    }
+
+    /// Get typed access to the samples, depending on the sample size of the wave.
+    pub fn data(&self) -> WaveData<'_> {
+        match self.0.sampleSize {
+            8 => WaveData::U8(self.samples().unwrap()),
+            16 => WaveData::I16(self.samples().unwrap()),
+            32 => WaveData::F32(self.samples().unwrap()),
+            _ => unreachable!("raylib only supports 8, 16 and 32 bit waves")
+        }
+    }
+
+    /// Get typed mutable access to the samples, depending on the sample size of the wave.
+    pub fn data_mut(&mut self) -> WaveDataMut<'_> {
+        match self.0.sampleSize {
+            8 => WaveDataMut::U8(self.samples_mut().unwrap()),
+            16 => WaveDataMut::I16(self.samples_mut().unwrap()),
+            32 => WaveDataMut::F32(self.samples_mut().unwrap()),
+            _ => unreachable!("raylib only supports 8, 16 and 32 bit waves")
+        }
+    }
+
+    /// Get the interleaved samples of the wave.
+    /// Returns `None` if `T` does not correspond to the sample size of the wave.
+    pub fn samples<T: Sample>(&self) -> Option<&[T]> {
+        if self.0.sampleSize != T::BITS { return None }
+        // SAFETY: the sample type matches the wave data, which contains `sample_count` samples.
+        Some(unsafe { std::slice::from_raw_parts(self.0.data as *const T, self.sample_count()) })
+    }
+
+    /// Get mutable access to the interleaved samples of the wave.
+    /// Returns `None` if `T` does not correspond to the sample size of the wave.
+    pub fn samples_mut<T: Sample>(&mut self) -> Option<&mut [T]> {
+        if self.0.sampleSize != T::BITS { return None }
+        // SAFETY: the sample type matches the wave data, which contains `sample_count` samples.
+        Some(unsafe { std::slice::from_raw_parts_mut(self.0.data as *mut T, self.sample_count()) })
+    }
+
+    /// Iterates over the frames of the wave.
+    /// Every item contains one sample per channel.
+    /// Returns `None` if `T` does not correspond to the sample size of the wave.
+    /// # Examples
+    /// Compute the peak of the left channel:
+    /// ```
+    /// # use raylib::prelude::*;
+    /// let wave = Wave::load("assets/coin.wav").unwrap().converted(44100, 32, 2);
+    /// let peak = wave.frames::<f32>().unwrap().map(|frame| frame[0].abs()).fold(0.0, f32::max);
+    /// ```
+    pub fn frames<T: Sample>(&self) -> Option<std::slice::ChunksExact<'_, T>> {
+        let channels = self.0.channels as usize;
+        self.samples().map(|s| s.chunks_exact(channels))
+    }
+
+    /// Iterates mutably over the frames of the wave.
+    /// Returns `None` if `T` does not correspond to the sample size of the wave.
+    pub fn frames_mut<T: Sample>(&mut self) -> Option<std::slice::ChunksExactMut<'_, T>> {
+        let channels = self.0.channels as usize;
+        self.samples_mut().map(|s| s.chunks_exact_mut(channels))
+    }
+
+    /// Loads the interleaved samples of the wave as floats, whatever the sample size.
+    /// Values are normalized to the `[-1.0; 1.0]` range.
+    pub fn load_samples(&self) -> Vec<f32> {
+        let ptr = unsafe { ffi::LoadWaveSamples(self.0) };
+        if ptr.is_null() { return Vec::new() }
+
+        let samples = unsafe { std::slice::from_raw_parts(ptr, self.sample_count()) }.to_vec();
+        unsafe { ffi::UnloadWaveSamples(ptr) }
+        samples
+    }
+}
+
+/// # Wave manipulation functions
+///
+/// ---
+impl Wave {
+    /// Crops the wave to the given frame range.
+    /// # Panics
+    /// Panics if the range is empty or out of bounds.
+    pub fn crop(&mut self, frames: std::ops::Range<u32>) {
+        assert!(frames.start < frames.end && frames.end <= self.0.frameCount, "crop range out of bounds");
+        unsafe { ffi::WaveCrop(&mut self.0 as *mut _, frames.start as i32, frames.end as i32) }
+    }
+
+    /// Converts the wave to the given sample rate, sample size and number of channels.
+    /// # Panics
+    /// Panics if `sample_size` is not 8, 16 or 32, or if `sample_rate` or `channels` are 0.
+    pub fn convert(&mut self, sample_rate: u32, sample_size: u32, channels: u32) {
+        assert!(matches!(sample_size, 8 | 16 | 32), "the sample size must be 8, 16 or 32 bits");
+        assert!(sample_rate > 0 && channels > 0);
+        unsafe { ffi::WaveFormat(&mut self.0 as *mut _, sample_rate as i32, sample_size as i32, channels as i32) }
+    }
+
+    /// Converts the wave to the given sample rate, sample size and number of channels.
+    /// See [`Wave::convert`].
+    pub fn converted(mut self, sample_rate: u32, sample_size: u32, channels: u32) -> Wave {
+        self.convert(sample_rate, sample_size, channels);
+        self
+    }
+
+    /// Resamples the wave to the given sample rate, keeping its sample size and channels.
+    pub fn resample(&mut self, sample_rate: u32) {
+        self.convert(sample_rate, self.0.sampleSize, self.0.channels)
+    }
+}
+
+/// # Wave export functions
+///
+/// ---
+impl Wave {
+    /// Exports the wave to a file.
+    /// The filetype is deduced from the extension: `.wav`, `.qoa` or `.raw`.
+    ///
+    /// Returns [`AudioError::UnsupportedFormat`] if support for the format was compiled out,
+    /// and [`AudioError::InvalidData`] if the wave could not be written (QOA only supports 16 bit waves).
+    pub fn export(&self, filename: impl AsRef<std::path::Path>) -> Result<(), AudioError> {
+        let filename = filename.as_ref();
+        let extension = filename.extension().and_then(|s| s.to_str()).map(|s| s.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("wav") => AudioFiletype::Wav.check_supported()?,
+            Some("qoa") => {
+                AudioFiletype::Qoa.check_supported()?;
+                if self.0.sampleSize != 16 { return Err(AudioError::InvalidData) }
+            }
+            Some("raw") => (),
+            _ => return Err(AudioError::UnknownFormat)
+        }
+
+        let cstr = CString::new(filename.as_os_str().as_encoded_bytes()).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        if unsafe { ffi::ExportWave(self.0, cstr.as_ptr()) } { Ok(()) }
+        else { Err(std::io::Error::other("failed to export wave").into()) }
+    }
+
+    /// Exports the wave as C code defining an array of samples (`.h` file).
+    pub fn export_as_code(&self, filename: impl AsRef<std::path::Path>) -> Result<(), AudioError> {
+        let cstr = CString::new(filename.as_ref().as_os_str().as_encoded_bytes()).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        if unsafe { ffi::ExportWaveAsCode(self.0, cstr.as_ptr()) } { Ok(()) }
+        else { Err(std::io::Error::other("failed to export wave").into()) }
+    }
+
+    /// Encodes the wave into a memory buffer.
+    ///
+    /// The WAV encoder is implemented in Rust, so it doesn't need the `wav` feature.
+    /// QOA uses raylib's encoder: it returns [`AudioError::UnsupportedFormat`] without the `qoa` feature,
+    /// and [`AudioError::InvalidData`] if the wave isn't 16 bit or has more than 8 channels.
+    pub fn export_to_memory(&self, filetype: WaveExportFiletype) -> Result<Vec<u8>, AudioError> {
+        let data = unsafe { std::slice::from_raw_parts(self.0.data as *const u8, self.sample_count() * self.0.sampleSize as usize / 8) };
+
+        match filetype {
+            WaveExportFiletype::Raw => Ok(data.to_vec()),
+            WaveExportFiletype::Wav => {
+                let block_align = self.0.channels * self.0.sampleSize / 8;
+                // 3 is WAVE_FORMAT_IEEE_FLOAT, 1 is WAVE_FORMAT_PCM
+                let format_tag: u16 = if self.0.sampleSize == 32 { 3 } else { 1 };
+
+                let mut out = Vec::with_capacity(44 + data.len());
+                out.extend_from_slice(b"RIFF");
+                out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+                out.extend_from_slice(b"WAVEfmt ");
+                out.extend_from_slice(&16u32.to_le_bytes());
+                out.extend_from_slice(&format_tag.to_le_bytes());
+                out.extend_from_slice(&(self.0.channels as u16).to_le_bytes());
+                out.extend_from_slice(&self.0.sampleRate.to_le_bytes());
+                out.extend_from_slice(&(self.0.sampleRate * block_align).to_le_bytes());
+                out.extend_from_slice(&(block_align as u16).to_le_bytes());
+                out.extend_from_slice(&(self.0.sampleSize as u16).to_le_bytes());
+                out.extend_from_slice(b"data");
+                out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+
+                // samples are stored in little endian
+                match self.data() {
+                    WaveData::U8(s) => out.extend_from_slice(s),
+                    WaveData::I16(s) => s.iter().for_each(|v| out.extend_from_slice(&v.to_le_bytes())),
+                    WaveData::F32(s) => s.iter().for_each(|v| out.extend_from_slice(&v.to_le_bytes())),
+                }
+                Ok(out)
+            }
+            WaveExportFiletype::Qoa => self.export_qoa_to_memory()
+        }
+    }
+
+    #[cfg(feature = "qoa")]
+    fn export_qoa_to_memory(&self) -> Result<Vec<u8>, AudioError> {
+        if self.0.sampleSize != 16 { return Err(AudioError::InvalidData) }
+
+        let mut desc = qoa::QoaDesc {
+            channels: self.0.channels,
+            samplerate: self.0.sampleRate,
+            samples: self.0.frameCount,
+            lms: [[0; 8]; 8]
+        };
+        let mut len = 0;
+        let ptr = unsafe { qoa::qoa_encode(self.0.data as *const i16, &mut desc, &mut len) };
+        if ptr.is_null() { return Err(AudioError::InvalidData) }
+
+        let out = unsafe { std::slice::from_raw_parts(ptr as *const u8, len as usize) }.to_vec();
+        unsafe { ffi::MemFree(ptr) }
+        Ok(out)
+    }
+
+    #[cfg(not(feature = "qoa"))]
+    fn export_qoa_to_memory(&self) -> Result<Vec<u8>, AudioError> {
+        Err(AudioError::UnsupportedFormat { feature: "qoa" })
+    }
+}
+
+impl Clone for Wave {
+    /// Copies the wave data into a new buffer (equivalent to `WaveCopy`).
+    fn clone(&self) -> Self {
+        let wave = unsafe { ffi::WaveCopy(self.0) };
+        Wave::from_ffi(wave).expect("failed to copy wave data")
+    }
+}
+
+impl Wave {
+    /// Consumes self and returns the internal `ffi::Wave` without freeing it
+    ///
+    /// # Safety
+    /// The returned wave needs to be manually freed with `UnloadWave`.
+    #[inline]
+    pub unsafe fn unwrap(self) -> ffi::Wave {
+        let this = std::mem::ManuallyDrop::new(self);
+        this.0
+    }
+
+    /// Get a reference to the underlying ffi wave
+    #[inline]
+    pub fn get_ffi(&self) -> &ffi::Wave {
+        &self.0
+    }
+}
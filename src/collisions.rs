@@ -1,6 +1,14 @@
 //! Module handling 2d and 3d collision code
+//!
+//! 2d collisions are part of raylib's `rshapes` module (`shapes` feature), and 3d collisions of `rmodels` (`models` feature).
 
-use crate::{ffi, prelude::{Vector2, Vector3, BoundingBox, Ray, Rectangle}};
+use crate::prelude::Vector3;
+#[cfg(any(feature = "shapes", feature = "models"))]
+use crate::ffi;
+#[cfg(feature = "shapes")]
+use crate::prelude::{Vector2, Rectangle};
+#[cfg(feature = "models")]
+use crate::prelude::{BoundingBox, Ray};
 
 /// Ray hit information.
 /// 
@@ -16,34 +24,42 @@ pub struct RayHit {
     pub distance: f32
 }
 
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_recs(rec1: Rectangle, rec2: Rectangle) -> bool {
     unsafe { ffi::CheckCollisionRecs(rec1, rec2) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_circles(center1: Vector2, radius1: f32, center2: Vector2, radius2: f32) -> bool {
     unsafe { ffi::CheckCollisionCircles(center1, radius1, center2, radius2) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_circle_rec(center: Vector2, radius: f32, rec: Rectangle) -> bool {
     unsafe { ffi::CheckCollisionCircleRec(center, radius, rec) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_point_rec(point: Vector2, rec: Rectangle) -> bool {
     unsafe { ffi::CheckCollisionPointRec(point, rec) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_point_circle(point: Vector2, center: Vector2, radius: f32) -> bool {
     unsafe { ffi::CheckCollisionPointCircle(point, center, radius) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_point_triangle(point: Vector2, p1: Vector2, p2: Vector2, p3: Vector2) -> bool {
     unsafe { ffi::CheckCollisionPointTriangle(point, p1, p2, p3) }
 }
+#[cfg(feature = "shapes")]
 #[inline]
 pub fn check_collision_point_poly(point: Vector2, points: &[Vector2]) -> bool {
     unsafe { ffi::CheckCollisionPointPoly(point, points.as_ptr().cast_mut(), points.len() as i32) }
 }
+#[cfg(feature = "shapes")]
 /// Returns `Some` with the coordinate if there is a collision and `None` if there wasn't any. 
 #[inline]
 pub fn check_collision_lines(start1: Vector2, end1: Vector2, start2: Vector2, end2: Vector2) -> Option<Vector2> {
@@ -51,11 +67,13 @@ pub fn check_collision_lines(start1: Vector2, end1: Vector2, start2: Vector2, en
     let col = unsafe { ffi::CheckCollisionLines(start1, end1, start2, end2, &mut p as *mut _) };
     if col { Some(p) } else { None }
 }
+#[cfg(feature = "shapes")]
 /// Checks if the given point belongs to the given line, within the given threshold in pixels
 #[inline]
 pub fn check_collision_point_line(point: Vector2, start: Vector2, end: Vector2, threshold: i32) -> bool {
    unsafe { ffi::CheckCollisionPointLine(point, start, end, threshold) }
 }
+#[cfg(feature = "shapes")]
 /// Get the intersection between two rectangles
 /// Returns None if the resulting intersection is empty
 #[inline]
@@ -67,21 +85,25 @@ pub fn get_collision_rec(rec1: Rectangle, rec2: Rectangle) -> Option<Rectangle>
     Some(rec)
 }
 
+#[cfg(feature = "models")]
 /// Check collision between two spheres
 #[inline]
 pub fn check_collision_spheres(center1: Vector3, radius1: f32, center2: Vector3, radius2: f32) -> bool {
     unsafe { ffi::CheckCollisionSpheres(center1, radius1, center2, radius2) }
 }
+#[cfg(feature = "models")]
 /// Check collision between two bounding boxes
 #[inline]
 pub fn check_collision_boxes(a: BoundingBox, b: BoundingBox) -> bool {
     unsafe { ffi::CheckCollisionBoxes(a, b) }
 }
+#[cfg(feature = "models")]
 /// Check collision between a box and a sphere
 #[inline]
 pub fn check_collision_box_sphere(bounds: BoundingBox, center: Vector3, radius: f32) -> bool {
     unsafe { ffi::CheckCollisionBoxSphere(bounds, center, radius) }
 }
+#[cfg(feature = "models")]
 /// Get collision info between a ray and a sphere
 #[inline]
 pub fn get_ray_collision_sphere(ray: Ray, center: Vector3, radius: f32) -> Option<RayHit> {
@@ -92,6 +114,7 @@ pub fn get_ray_collision_sphere(ray: Ray, center: Vector3, radius: f32) -> Optio
         distance: col.distance
     })
 }
+#[cfg(feature = "models")]
 /// Get collision info between a ray and a box
 #[inline]
 pub fn get_ray_collision_box(ray: Ray, bounds: BoundingBox) -> Option<RayHit> {
@@ -114,6 +137,7 @@ pub fn get_ray_collision_box(ray: Ray, bounds: BoundingBox) -> Option<RayHit> {
 //     })
 // }
 
+#[cfg(feature = "models")]
 /// Get collision info between a ray and a triangle
 #[inline]
 pub fn get_ray_collision_triangle(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3) -> Option<RayHit> {
@@ -124,6 +148,7 @@ pub fn get_ray_collision_triangle(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector
         distance: col.distance
     })
 }
+#[cfg(feature = "models")]
 /// Get collision info between a ray and a quad
 #[inline]
 pub fn get_ray_collision_quad(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3, p4: Vector3) -> Option<RayHit> {
@@ -0,0 +1,186 @@
+//! An optional structure for the game loop.
+//!
+//! Implement [`App`] and give it to [`run`], which creates the window and calls the app every frame.
+//! Game logic can run in [`App::fixed_update`], at a constant rate independent from the framerate,
+//! and be interpolated when drawing.
+
+use crate::Error;
+
+use super::{draw::{begin_drawing, DrawHandle}, other::get_time_f64, window::{get_screen_size, is_window_resized, window_should_close, WindowBuilder}, Raylib};
+
+/// A game driven by [`run`].
+///
+/// Every frame, the driver calls [`App::fixed_update`] as many times as needed to catch up with the time that passed,
+/// then [`App::update`], and finally [`App::draw`].
+/// Call [`quit`](super::window::quit) to stop the loop at the end of the frame.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// struct Ball { previous: Vector2, position: Vector2, velocity: Vector2 }
+///
+/// impl App for Ball {
+///     fn fixed_update(&mut self, _rl: &mut Raylib, dt: f32) {
+///         self.previous = self.position;
+///         self.position = self.position + self.velocity * dt;
+///     }
+///
+///     fn update(&mut self, rl: &mut Raylib, _dt: f32) {
+///         if is_key_pressed(rl, Key::Q) { quit(rl) }
+/// #       quit(rl); // only run one frame in the doctest
+///     }
+///
+///     fn draw(&mut self, rl: &mut DrawHandle, alpha: f32) {
+///         clear_background(rl, Color::RAYWHITE);
+///         // draw between the last two physics steps, for a smooth movement at any framerate
+///         draw_circle_v(rl, self.previous.lerp(self.position, alpha), 20.0, Color::RED);
+///     }
+/// }
+///
+/// let ball = Ball { previous: Vector2::ZERO, position: Vector2::ZERO, velocity: vec2(50.0, 30.0) };
+/// run(ball, WindowBuilder::new(800, 600, "Ball").vsync(true)).unwrap();
+/// ```
+pub trait App {
+    /// Time between two calls to [`App::fixed_update`], in seconds.
+    const FIXED_TIMESTEP: f64 = 1.0 / 60.0;
+    /// Maximum number of calls to [`App::fixed_update`] in a single frame.
+    /// After a long frame (or when the game is too slow), the time that can't be caught up on is dropped,
+    /// instead of taking longer and longer to catch up (the "spiral of death").
+    const MAX_FIXED_STEPS: u32 = 5;
+
+    /// Called once, after the window is created.
+    fn init(&mut self, rl: &mut Raylib) { let _ = rl; }
+    /// Called once per frame, `dt` is the time since the previous frame in seconds.
+    fn update(&mut self, rl: &mut Raylib, dt: f32) { let _ = (rl, dt); }
+    /// Called every [`App::FIXED_TIMESTEP`] seconds (possibly multiple times per frame), `dt` is the timestep.
+    fn fixed_update(&mut self, rl: &mut Raylib, dt: f32) { let _ = (rl, dt); }
+    /// Draws the frame.
+    /// `alpha`, between 0 and 1, is how far the time is between the last fixed update and the next,
+    /// to interpolate the state updated in [`App::fixed_update`].
+    fn draw(&mut self, rl: &mut DrawHandle, alpha: f32);
+    /// Called when the window is resized, with its new size.
+    fn on_resize(&mut self, rl: &mut Raylib, width: f32, height: f32) { let _ = (rl, width, height); }
+    /// Called once the loop stopped, before the window closes.
+    fn on_exit(&mut self, rl: &mut Raylib) { let _ = rl; }
+}
+
+/// A source of time for [`run_with_clock`], in seconds.
+///
+/// Implemented for closures returning the time.
+pub trait Clock {
+    fn now(&mut self, rl: &Raylib) -> f64;
+}
+
+/// The time since the window was created, from [`get_time_f64`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RaylibClock;
+
+impl Clock for RaylibClock {
+    fn now(&mut self, rl: &Raylib) -> f64 {
+        get_time_f64(rl)
+    }
+}
+
+impl<F: FnMut() -> f64> Clock for F {
+    fn now(&mut self, _: &Raylib) -> f64 {
+        self()
+    }
+}
+
+/// What to do for a frame, returned by [`FixedTimestep::advance`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    /// Time since the previous frame, in seconds.
+    pub dt: f64,
+    /// Number of fixed steps to run.
+    pub steps: u32,
+    /// How far the time is between the last step and the next, between 0 and 1.
+    pub alpha: f64
+}
+
+/// Fixed timestep accumulator, used by [`run`].
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut timestep = FixedTimestep::new(0.1, 5);
+/// assert_eq!(timestep.advance(1.0).steps, 0);
+///
+/// let tick = timestep.advance(1.25);
+/// assert_eq!(tick.steps, 2);
+/// assert!((tick.alpha - 0.5).abs() < 1e-9);
+///
+/// // the time of a long pause isn't caught up on
+/// let tick = timestep.advance(60.02);
+/// assert_eq!(tick.steps, 5);
+/// assert_eq!(timestep.advance(60.05).steps, 0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedTimestep {
+    step: f64,
+    max_steps: u32,
+    accumulator: f64,
+    last: Option<f64>
+}
+
+impl FixedTimestep {
+    /// Creates an accumulator running steps of `step` seconds, at most `max_steps` per frame.
+    ///
+    /// # Panics
+    /// Panics if `step` is not strictly positive.
+    pub fn new(step: f64, max_steps: u32) -> Self {
+        assert!(step > 0.0, "the timestep must be positive");
+        FixedTimestep { step, max_steps, accumulator: 0.0, last: None }
+    }
+
+    /// The time between two steps, in seconds.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Advances to the time `now` (in seconds), and returns the number of steps to run.
+    /// The first call only starts the clock.
+    pub fn advance(&mut self, now: f64) -> Tick {
+        let dt = self.last.map_or(0.0, |last| (now - last).max(0.0));
+        self.last = Some(now);
+        self.accumulator += dt;
+
+        let steps = (self.accumulator / self.step).floor();
+        self.accumulator = (self.accumulator - steps * self.step).max(0.0);
+
+        // the steps that can't be caught up on are dropped, only the fraction of a step is kept for the interpolation
+        let steps = (steps as u32).min(self.max_steps);
+        Tick { dt, steps, alpha: (self.accumulator / self.step).clamp(0.0, 1.0) }
+    }
+}
+
+/// Creates the window and runs `app` until it is closed (or [`quit`](super::window::quit) is called).
+///
+/// Returns an error if the window could not be created.
+/// See [`App`] for an example.
+pub fn run(app: impl App, builder: WindowBuilder) -> Result<(), Error> {
+    run_with_clock(app, builder, RaylibClock)
+}
+
+/// Same as [`run`], but reading the time from `clock`.
+pub fn run_with_clock<A: App>(mut app: A, builder: WindowBuilder, mut clock: impl Clock) -> Result<(), Error> {
+    let rl = &mut builder.build()?;
+    app.init(rl);
+
+    let mut timestep = FixedTimestep::new(A::FIXED_TIMESTEP, A::MAX_FIXED_STEPS);
+    while !window_should_close(rl) {
+        if is_window_resized(rl) {
+            let size = get_screen_size(rl);
+            app.on_resize(rl, size.x, size.y);
+        }
+
+        let tick = timestep.advance(clock.now(rl));
+        for _ in 0..tick.steps {
+            app.fixed_update(rl, timestep.step() as f32);
+        }
+        app.update(rl, tick.dt as f32);
+
+        begin_drawing(rl, |rl| app.draw(rl, tick.alpha as f32));
+    }
+
+    app.on_exit(rl);
+    Ok(())
+}
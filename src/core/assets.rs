@@ -0,0 +1,336 @@
+//! Hot-reloading of assets while the game runs.
+//!
+//! An [`AssetManager`] loads shaders, textures, fonts and models, and hands out [`Handle`]s to them.
+//! Calling [`AssetManager::update`] between frames reloads the files that changed on the disk,
+//! so shaders and textures can be edited without restarting the game.
+//! Handles stay valid across reloads, they always point to the latest version of the asset.
+//!
+//! When a reload fails (a shader that doesn't compile, a texture that is still being written, ...),
+//! the previous version of the asset is kept, and the error is reported by [`AssetManager::update`].
+
+use std::{any::type_name, fmt, hash::{Hash, Hasher}, marker::PhantomData, path::{Path, PathBuf}, time::{Duration, Instant, SystemTime}};
+
+use crate::{prelude::{Raylib, Shader}, Error};
+#[cfg(feature = "textures")]
+use crate::prelude::Texture;
+#[cfg(feature = "text")]
+use crate::prelude::{load_font, TrueTypeFontCache};
+#[cfg(feature = "models")]
+use crate::model::model::Model;
+
+/// A reference to an asset owned by an [`AssetManager`].
+///
+/// Handles are cheap to copy, and stay valid when the asset is reloaded.
+pub struct Handle<T> {
+    index: usize,
+    _marker: PhantomData<fn() -> T>
+}
+
+impl<T> Handle<T> {
+    fn new(index: usize) -> Self {
+        Handle { index, _marker: PhantomData }
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state)
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Handle<{}>({})", type_name::<T>(), self.index)
+    }
+}
+
+type Loader<T> = Box<dyn Fn(&mut Raylib) -> Result<T, Error>>;
+
+struct Entry<T> {
+    asset: T,
+    /// Files the asset is loaded from, with their modification time when it was last loaded.
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+    loader: Loader<T>
+}
+
+impl<T> Entry<T> {
+    fn changed(&self) -> bool {
+        self.files.iter().any(|(path, time)| modified(path) != *time)
+    }
+
+    fn reload(&mut self, rl: &mut Raylib) -> Result<(), Error> {
+        // read the times before loading, so that a change during the load is picked up by the next update
+        let times: Vec<_> = self.files.iter().map(|(path, _)| modified(path)).collect();
+        let result = (self.loader)(rl);
+
+        // the new times are kept even if loading failed, to only report each error once
+        for ((_, time), new) in self.files.iter_mut().zip(times) {
+            *time = new;
+        }
+        self.asset = result?;
+        Ok(())
+    }
+}
+
+mod private {
+    use std::path::PathBuf;
+
+    use super::{modified, AssetManager, Entry, Error, Handle, Loader, Raylib};
+
+    /// Storage for one type of asset.
+    pub struct Store<T> {
+        pub(super) entries: Vec<Entry<T>>
+    }
+
+    impl<T> Default for Store<T> {
+        fn default() -> Self {
+            Store { entries: Vec::new() }
+        }
+    }
+
+    impl<T> Store<T> {
+        pub(super) fn insert(&mut self, rl: &mut Raylib, files: Vec<PathBuf>, loader: Loader<T>) -> Result<Handle<T>, Error> {
+            let files: Vec<_> = files.into_iter().map(|path| { let time = modified(&path); (path, time) }).collect();
+            let asset = loader(rl)?;
+            self.entries.push(Entry { asset, files, loader });
+            Ok(Handle::new(self.entries.len() - 1))
+        }
+    }
+
+    pub trait Stored: Sized {
+        fn store(manager: &AssetManager) -> &Store<Self>;
+        fn store_mut(manager: &mut AssetManager) -> &mut Store<Self>;
+    }
+}
+
+use private::Store;
+
+/// Types of assets managed by an [`AssetManager`]: [`Shader`], `Texture`, `TrueTypeFontCache` and `Model`
+/// (with the `textures`, `text` and `models` features).
+pub trait ManagedAsset: private::Stored + 'static {}
+
+macro_rules! managed_asset {
+    ($($(#[$attr:meta])* $ty:ty => $field:ident),*) => {$(
+        $(#[$attr])*
+        impl ManagedAsset for $ty {}
+        $(#[$attr])*
+        impl private::Stored for $ty {
+            fn store(manager: &AssetManager) -> &Store<Self> { &manager.$field }
+            fn store_mut(manager: &mut AssetManager) -> &mut Store<Self> { &mut manager.$field }
+        }
+    )*};
+}
+
+managed_asset!(
+    Shader => shaders,
+    #[cfg(feature = "textures")] Texture => textures,
+    #[cfg(feature = "text")] TrueTypeFontCache => fonts,
+    #[cfg(feature = "models")] Model => models
+);
+
+/// The result of reloading a file in [`AssetManager::update`].
+#[derive(Debug)]
+pub struct Reload {
+    /// The files the asset is loaded from.
+    pub files: Vec<PathBuf>,
+    /// `Err` if the asset failed to reload (the previous version is kept).
+    pub result: Result<(), Error>
+}
+
+/// Loads assets, and reloads them when their files change.
+///
+/// Reloading needs a `&mut Raylib`, so it can only happen between frames (outside of [`begin_drawing`](crate::core::draw::begin_drawing)),
+/// where no asset is in use.
+///
+/// Modification times are read from the disk: assets loaded from a [virtual file system](super::vfs) that isn't backed by the disk are never reloaded.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut init_window(800, 800, "Hot reloading", 60);
+/// let mut assets = AssetManager::new();
+/// let light = assets.load_shader(rl, Some("assets/light.vs"), Some("assets/light.fs")).unwrap();
+/// let font = assets.load_font(rl, "assets/TerminusTTF.ttf").unwrap();
+/// while !window_should_close(rl) {
+///     for reload in assets.update(rl) {
+///         if let Err(e) = reload.result {
+///             println!("failed to reload {:?}: {e}", reload.files);
+///         }
+///     }
+///
+///     begin_drawing(rl, |rl| {
+///         clear_background(rl, Color::RAYWHITE);
+///         begin_shader_mode(rl, &assets[light], |rl| {
+///             draw_rectangle(rl, 100.0, 100.0, 200.0, 200.0, Color::RED);
+///         });
+///         draw_text(rl, &assets[font], "Edit the shader!", vec2(20.0, 20.0), 20.0, Color::BLACK);
+///     });
+///     # break
+/// }
+/// ```
+pub struct AssetManager {
+    shaders: Store<Shader>,
+    #[cfg(feature = "textures")]
+    textures: Store<Texture>,
+    #[cfg(feature = "text")]
+    fonts: Store<TrueTypeFontCache>,
+    #[cfg(feature = "models")]
+    models: Store<Model>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>
+}
+
+impl fmt::Debug for AssetManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("AssetManager");
+        debug.field("shaders", &self.shaders.entries.len());
+        #[cfg(feature = "textures")]
+        debug.field("textures", &self.textures.entries.len());
+        #[cfg(feature = "text")]
+        debug.field("fonts", &self.fonts.entries.len());
+        #[cfg(feature = "models")]
+        debug.field("models", &self.models.entries.len());
+        debug.field("poll_interval", &self.poll_interval).finish()
+    }
+}
+
+impl Default for AssetManager {
+    fn default() -> Self {
+        AssetManager::new()
+    }
+}
+
+impl AssetManager {
+    /// Creates an empty manager, checking for changes every 250 milliseconds.
+    pub fn new() -> Self {
+        AssetManager {
+            shaders: Store::default(),
+            #[cfg(feature = "textures")]
+            textures: Store::default(),
+            #[cfg(feature = "text")]
+            fonts: Store::default(),
+            #[cfg(feature = "models")]
+            models: Store::default(),
+            poll_interval: Duration::from_millis(250),
+            last_poll: None
+        }
+    }
+
+    /// Sets how often [`AssetManager::update`] checks the modification time of the files.
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        self.poll_interval = interval;
+    }
+
+    /// Loads a shader from a vertex and a fragment shader file (the default shader is used for `None`s).
+    /// Returns [`Error::ShaderCompile`] if the shader fails to compile.
+    pub fn load_shader<P: AsRef<Path>>(&mut self, rl: &mut Raylib, vs_file_name: Option<P>, fs_file_name: Option<P>) -> Result<Handle<Shader>, Error> {
+        let vs = vs_file_name.map(|path| path.as_ref().to_path_buf());
+        let fs = fs_file_name.map(|path| path.as_ref().to_path_buf());
+        let files = vs.iter().chain(&fs).cloned().collect();
+
+        self.shaders.insert(rl, files, Box::new(move |rl| Shader::load(rl, vs.as_ref(), fs.as_ref())))
+    }
+
+    /// Loads a texture from an image file.
+    #[cfg(feature = "textures")]
+    pub fn load_texture(&mut self, rl: &mut Raylib, path: impl AsRef<Path>) -> Result<Handle<Texture>, Error> {
+        let path = path.as_ref().to_path_buf();
+        self.textures.insert(rl, vec![path.clone()], Box::new(move |rl| Texture::load(rl, &path)))
+    }
+
+    /// Loads a font from a `.ttf` or `.otf` file, with default [`FontParams`](crate::text::font::FontParams).
+    #[cfg(feature = "text")]
+    pub fn load_font(&mut self, rl: &mut Raylib, path: impl AsRef<Path>) -> Result<Handle<TrueTypeFontCache>, Error> {
+        let path = path.as_ref().to_path_buf();
+        self.fonts.insert(rl, vec![path.clone()], Box::new(move |rl| load_font(rl, &path)))
+    }
+
+    /// Loads a model (meshes and materials).
+    /// Only the model file is watched, changes to its materials or textures aren't picked up.
+    #[cfg(feature = "models")]
+    pub fn load_model(&mut self, rl: &mut Raylib, path: impl AsRef<Path>) -> Result<Handle<Model>, Error> {
+        let path = path.as_ref().to_path_buf();
+        self.models.insert(rl, vec![path.clone()], Box::new(move |_| Model::load(&path)))
+    }
+
+    /// Gets the current version of an asset.
+    /// Assets can also be accessed by indexing the manager with the handle (`assets[handle]`).
+    pub fn get<T: ManagedAsset>(&self, handle: Handle<T>) -> &T {
+        &T::store(self).entries[handle.index].asset
+    }
+    pub fn get_mut<T: ManagedAsset>(&mut self, handle: Handle<T>) -> &mut T {
+        &mut T::store_mut(self).entries[handle.index].asset
+    }
+
+    /// Files an asset is loaded from.
+    pub fn files<T: ManagedAsset>(&self, handle: Handle<T>) -> impl Iterator<Item = &Path> {
+        T::store(self).entries[handle.index].files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Reloads an asset, even if its files didn't change.
+    /// The previous version is kept if it fails.
+    pub fn reload<T: ManagedAsset>(&mut self, rl: &mut Raylib, handle: Handle<T>) -> Result<(), Error> {
+        T::store_mut(self).entries[handle.index].reload(rl)
+    }
+
+    /// Reloads the assets whose files changed since they were loaded.
+    /// Call this once per frame, outside of drawing.
+    ///
+    /// Returns the reloaded assets, and wether they were reloaded successfully.
+    pub fn update(&mut self, rl: &mut Raylib) -> Vec<Reload> {
+        let now = Instant::now();
+        if self.last_poll.is_some_and(|last| now.duration_since(last) < self.poll_interval) {
+            return Vec::new()
+        }
+        self.last_poll = Some(now);
+
+        let mut reloads = Vec::new();
+        reload_changed(rl, &mut self.shaders, &mut reloads);
+        #[cfg(feature = "textures")]
+        reload_changed(rl, &mut self.textures, &mut reloads);
+        #[cfg(feature = "text")]
+        reload_changed(rl, &mut self.fonts, &mut reloads);
+        #[cfg(feature = "models")]
+        reload_changed(rl, &mut self.models, &mut reloads);
+        reloads
+    }
+}
+
+impl<T: ManagedAsset> std::ops::Index<Handle<T>> for AssetManager {
+    type Output = T;
+    fn index(&self, handle: Handle<T>) -> &T {
+        self.get(handle)
+    }
+}
+
+impl<T: ManagedAsset> std::ops::IndexMut<Handle<T>> for AssetManager {
+    fn index_mut(&mut self, handle: Handle<T>) -> &mut T {
+        self.get_mut(handle)
+    }
+}
+
+fn reload_changed<T>(rl: &mut Raylib, store: &mut Store<T>, reloads: &mut Vec<Reload>) {
+    for entry in store.entries.iter_mut().filter(|entry| entry.changed()) {
+        let result = entry.reload(rl);
+        let files = entry.files.iter().map(|(path, _)| path.clone()).collect();
+        reloads.push(Reload { files, result });
+    }
+}
+
+/// Modification time of a file, `None` if it doesn't exist (or isn't on the disk).
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
@@ -7,7 +7,9 @@
 
 use std::ops::{Deref, DerefMut};
 
-use crate::{ffi::{self, Camera2D, VrStereoConfig, BlendMode}, prelude::{Color, RenderTexture, Shader}};
+use crate::{ffi::{self, Camera2D, VrStereoConfig, BlendMode}, prelude::{Color, Shader}};
+#[cfg(feature = "textures")]
+use crate::prelude::RenderTexture;
 use super::Raylib;
 
 pub struct DrawHandle<'a> {
@@ -29,6 +31,42 @@ pub fn begin_drawing(rl: &mut Raylib, f: impl FnOnce(&mut DrawHandle)) {
     let mut d = DrawHandle { rl };
     f(&mut d);
     unsafe { ffi::EndDrawing() }
+
+    #[cfg(feature = "custom_frame_control")]
+    super::other::frame_control::end_frame(rl);
+}
+
+/// Setup canvas (framebuffer) to start drawing, without showing the frame at the end.
+///
+/// Unlike [`begin_drawing`], this doesn't swap the buffers, wait for the target FPS or poll input events:
+/// call [`swap_screen_buffer`](super::other::swap_screen_buffer) and [`poll_input_events`](super::other::poll_input_events) when needed.
+/// [`get_frame_time`](super::other::get_frame_time) is only updated by [`begin_drawing`].
+/// # Usage
+/// Simulate at 240Hz, but only render at 60Hz:
+/// ```
+/// # use raylib::prelude::*;
+/// # let rl = &mut init_window(100, 100, "", 0);
+/// let mut previous_render = get_time_f64(rl);
+/// while !window_should_close(rl) {
+///     poll_input_events(rl);
+///     // update...
+///     if get_time_f64(rl) - previous_render >= 1.0 / 60.0 {
+///         begin_drawing_manual(rl, |rl| {
+///             clear_background(rl, Color::WHITE);
+///         });
+///         swap_screen_buffer(rl);
+///         previous_render = get_time_f64(rl);
+///     }
+///     wait_time_f64(rl, 1.0 / 240.0);
+///     # break
+/// }
+/// ```
+#[cfg(feature = "custom_frame_control")]
+pub fn begin_drawing_manual(rl: &mut Raylib, f: impl FnOnce(&mut DrawHandle)) {
+    unsafe { ffi::BeginDrawing() }
+    let mut d = DrawHandle { rl };
+    f(&mut d);
+    unsafe { ffi::EndDrawing() }
 }
 
 /// Start drawing to a render texture
@@ -50,6 +88,7 @@ pub fn begin_drawing(rl: &mut Raylib, f: impl FnOnce(&mut DrawHandle)) {
 ///     # break;
 /// }
 /// ```
+#[cfg(feature = "textures")]
 pub fn begin_texture_mode(rl: &mut Raylib, target: &mut RenderTexture, f: impl FnOnce(&mut DrawHandle)) {
     unsafe { ffi::BeginTextureMode(target.get_ffi_texture()) }
     let mut d = DrawHandle { rl };
@@ -0,0 +1,73 @@
+//! Offscreen rendering for [`init_headless`](super::window::init_headless), with the GLFW platform.
+//!
+//! GLFW's null platform doesn't open windows or connect to a display. Its OpenGL contexts are created with EGL,
+//! on Mesa's surfaceless platform when available, which renders on the cpu with llvmpipe when there is no gpu.
+//! Raylib's copy of glfw needs `patches/0003-glfw-null-platform-egl.patch` for this (it can't select the null platform otherwise).
+//!
+//! The platform is chosen with glfw's functions, which an installed raylib doesn't export:
+//! this is only available with the vendored raylib.
+
+#[cfg(raylib_vendored)]
+use std::ffi::{c_char, c_int, c_void, CStr};
+
+#[cfg(raylib_vendored)]
+const GLFW_PLATFORM: c_int = 0x00050003;
+#[cfg(raylib_vendored)]
+const GLFW_ANY_PLATFORM: c_int = 0x00060000;
+#[cfg(raylib_vendored)]
+const GLFW_PLATFORM_NULL: c_int = 0x00060005;
+#[cfg(raylib_vendored)]
+const GLFW_VISIBLE: c_int = 0x00020004;
+
+#[cfg(raylib_vendored)]
+extern "C" {
+    fn glfwInitHint(hint: c_int, value: c_int);
+    fn glfwInit() -> c_int;
+    fn glfwTerminate();
+    fn glfwDefaultWindowHints();
+    fn glfwWindowHint(hint: c_int, value: c_int);
+    fn glfwCreateWindow(width: c_int, height: c_int, title: *const c_char, monitor: *mut c_void, share: *mut c_void) -> *mut c_void;
+    fn glfwDestroyWindow(window: *mut c_void);
+}
+
+/// Makes the next initialization of raylib use the null platform.
+/// Returns `false` if it can't be used.
+#[cfg(raylib_vendored)]
+pub(super) fn select_null_platform() -> bool {
+    unsafe { glfwInitHint(GLFW_PLATFORM, GLFW_PLATFORM_NULL) }
+    let available = context_available();
+    if !available { reset_platform() }
+    available
+}
+
+/// An installed raylib doesn't export glfw's functions, so the platform can't be chosen.
+#[cfg(not(raylib_vendored))]
+pub(super) fn select_null_platform() -> bool {
+    false
+}
+
+/// Lets glfw choose the platform again, init hints are kept across initializations.
+pub(super) fn reset_platform() {
+    #[cfg(raylib_vendored)]
+    unsafe { glfwInitHint(GLFW_PLATFORM, GLFW_ANY_PLATFORM) }
+}
+
+/// Creates a window with the selected platform, to check that the platform and its graphics context are available
+/// (EGL and a driver, or a glfw without the null platform).
+/// Raylib doesn't stop when the graphics context can't be created, and crashes on the first OpenGL call.
+#[cfg(raylib_vendored)]
+fn context_available() -> bool {
+    use crate::cstr;
+
+    if unsafe { glfwInit() } == 0 { return false }
+
+    unsafe {
+        glfwDefaultWindowHints();
+        glfwWindowHint(GLFW_VISIBLE, 0);
+    }
+    let window = unsafe { glfwCreateWindow(1, 1, cstr!("probe").as_ptr(), std::ptr::null_mut(), std::ptr::null_mut()) };
+    if !window.is_null() { unsafe { glfwDestroyWindow(window) } }
+
+    unsafe { glfwTerminate() }
+    !window.is_null()
+}
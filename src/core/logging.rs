@@ -0,0 +1,247 @@
+//! Forwarding raylib's log messages to Rust.
+//!
+//! Raylib reports what it's doing (opening the window, loading files, compiling shaders, ...) with `TraceLog`,
+//! which prints to stdout by default.
+//! The functions in this module format those messages, and send them somewhere else:
+//! - [`set_trace_log_handler`] gives every message to a closure.
+//! - [`forward_trace_log_to_log`] (with the `log` feature) sends them to the [`log`](https://docs.rs/log) crate, with the `raylib` target.
+//! - [`forward_trace_log_to_tracing`] (with the `tracing` feature) emits them as [`tracing`](https://docs.rs/tracing) events, with the `raylib` target.
+//! - [`TraceLogCapture`] collects them in memory, to check them in tests.
+//!
+//! Messages under the level set with [`set_trace_log_level`](super::other::set_trace_log_level) (`Info` by default)
+//! are filtered out by raylib, and never reach Rust.
+
+use std::{ffi::{c_char, c_int, CStr, CString}, panic::{catch_unwind, AssertUnwindSafe}, sync::{atomic::{AtomicI32, AtomicU64, Ordering}, Arc, Mutex, MutexGuard}, thread::{self, ThreadId}};
+
+use crate::{cstr, ffi::{self, TraceLogLevel}};
+
+type Handler = Arc<dyn Fn(TraceLogLevel, &str) + Send + Sync>;
+type Messages = Vec<(TraceLogLevel, String)>;
+
+/// Where messages go when no capture is active.
+#[derive(Clone)]
+enum Sink {
+    /// Unformatted message, see [`set_trace_log_callback`](super::other::set_trace_log_callback).
+    Raw(fn(TraceLogLevel, &str, va_list::VaList)),
+    Formatted(Handler)
+}
+
+static SINK: Mutex<Option<Sink>> = Mutex::new(None);
+/// Active captures with the thread that started them, messages go to the last one started by the logging thread.
+static CAPTURES: Mutex<Vec<(u64, ThreadId, Messages)>> = Mutex::new(Vec::new());
+static NEXT_CAPTURE: AtomicU64 = AtomicU64::new(0);
+/// The level given to [`set_trace_log_level`](super::other::set_trace_log_level), raylib doesn't expose it.
+pub(crate) static TRACE_LOG_LEVEL: AtomicI32 = AtomicI32::new(TraceLogLevel::Info as i32);
+
+#[allow(clashing_extern_declarations)]
+extern "C" {
+    // Same function as in `ffi`, but allowing NULL to restore raylib's default logging.
+    #[link_name = "SetTraceLogCallback"]
+    fn SetTraceLogCallbackNullable(callback: Option<ffi::TraceLogCallback>);
+    fn vsnprintf(buffer: *mut c_char, size: usize, format: *const c_char, args: va_list::VaList) -> c_int;
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // handlers are called without holding the locks, but a thread panicking while logging shouldn't disable logging
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Formats a printf-style message (as given to trace log callbacks).
+/// Messages longer than 4096 bytes are truncated.
+pub(crate) fn format_message(format: *const c_char, args: va_list::VaList) -> String {
+    let mut buffer = [0u8; 4096];
+    let written = unsafe { vsnprintf(buffer.as_mut_ptr() as *mut c_char, buffer.len(), format, args) };
+    let length = (written.max(0) as usize).min(buffer.len() - 1);
+    String::from_utf8_lossy(&buffer[..length]).into_owned()
+}
+
+extern "C" fn trace_log_callback(level: c_int, format: *const c_char, args: va_list::VaList) {
+    let Ok(level) = TraceLogLevel::try_from(level) else { return };
+
+    // locks are released before calling user code, which may log itself
+    let thread = thread::current().id();
+    let capturing = lock(&CAPTURES).iter().any(|(_, owner, _)| *owner == thread);
+    let sink = lock(&SINK).clone();
+
+    // unwinding into raylib would abort the process: the message of a panicking handler is dropped instead
+    if let (false, Some(Sink::Raw(callback))) = (capturing, &sink) {
+        let format = unsafe { CStr::from_ptr(format) }.to_string_lossy();
+        let _ = catch_unwind(AssertUnwindSafe(|| callback(level, &format, args)));
+        return
+    }
+
+    let message = format_message(format, args);
+    if let Some((_, _, messages)) = lock(&CAPTURES).iter_mut().rev().find(|(_, owner, _)| *owner == thread) {
+        return messages.push((level, message))
+    }
+    if let Some(Sink::Formatted(handler)) = sink {
+        let _ = catch_unwind(AssertUnwindSafe(|| handler(level, &message)));
+    }
+}
+
+/// Installs the callback in raylib if something needs it, or restores raylib's default logging.
+fn update_callback() {
+    let active = lock(&SINK).is_some() || !lock(&CAPTURES).is_empty();
+    if active {
+        unsafe { ffi::SetTraceLogCallback(trace_log_callback) }
+    } else {
+        unsafe { SetTraceLogCallbackNullable(None) }
+    }
+}
+
+pub(crate) fn set_raw_callback(callback: fn(TraceLogLevel, &str, va_list::VaList)) {
+    *lock(&SINK) = Some(Sink::Raw(callback));
+    update_callback();
+}
+
+/// Sends raylib's log messages to `handler`, already formatted, instead of printing them.
+/// Replaces any previous handler or [callback](super::other::set_trace_log_callback).
+///
+/// If the handler panics, the panic is caught (it can't unwind into raylib) and the message is dropped.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// set_trace_log_handler(|level, message| {
+///     if level == TraceLogLevel::Warning {
+///         eprintln!("raylib warning: {message}");
+///     }
+/// });
+/// let rl = &mut init_window(800, 800, "Logging", 60);
+/// ```
+pub fn set_trace_log_handler(handler: impl Fn(TraceLogLevel, &str) + Send + Sync + 'static) {
+    *lock(&SINK) = Some(Sink::Formatted(Arc::new(handler)));
+    update_callback();
+}
+
+/// Removes the log handler or callback, going back to raylib printing messages to stdout.
+pub fn reset_trace_log_handler() {
+    *lock(&SINK) = None;
+    update_callback();
+}
+
+/// Sends raylib's log messages to the `log` crate, with the `raylib` target.
+/// `Warning` maps to [`log::Level::Warn`], and `Fatal` to [`log::Level::Error`].
+///
+/// Raylib still filters messages under its own level, so set it with [`set_trace_log_level`](super::other::set_trace_log_level)
+/// to see debug messages.
+#[cfg(feature = "log")]
+pub fn forward_trace_log_to_log() {
+    set_trace_log_handler(|level, message| {
+        let level = match level {
+            TraceLogLevel::Trace => log::Level::Trace,
+            TraceLogLevel::Debug => log::Level::Debug,
+            TraceLogLevel::Info => log::Level::Info,
+            TraceLogLevel::Warning => log::Level::Warn,
+            TraceLogLevel::Error | TraceLogLevel::Fatal => log::Level::Error,
+            TraceLogLevel::All | TraceLogLevel::None => return
+        };
+        log::log!(target: "raylib", level, "{message}");
+    })
+}
+
+/// Emits raylib's log messages as `tracing` events, with the `raylib` target.
+/// `Warning` maps to [`tracing::Level::WARN`], and `Fatal` to [`tracing::Level::ERROR`].
+///
+/// Raylib still filters messages under its own level, so set it with [`set_trace_log_level`](super::other::set_trace_log_level)
+/// to see debug messages.
+#[cfg(feature = "tracing")]
+pub fn forward_trace_log_to_tracing() {
+    set_trace_log_handler(|level, message| match level {
+        TraceLogLevel::Trace => tracing::trace!(target: "raylib", "{message}"),
+        TraceLogLevel::Debug => tracing::debug!(target: "raylib", "{message}"),
+        TraceLogLevel::Info => tracing::info!(target: "raylib", "{message}"),
+        TraceLogLevel::Warning => tracing::warn!(target: "raylib", "{message}"),
+        TraceLogLevel::Error | TraceLogLevel::Fatal => tracing::error!(target: "raylib", "{message}"),
+        TraceLogLevel::All | TraceLogLevel::None => {}
+    })
+}
+
+/// Collects raylib's log messages in memory while it is alive.
+///
+/// Only messages logged by the thread that started the capture are collected,
+/// so tests running in parallel don't see each other's messages.
+/// Captured messages are not printed or sent to the log handler.
+/// Captures can be nested, messages go to the most recently started one.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let capture = TraceLogCapture::start();
+/// trace_log(TraceLogLevel::Warning, "something went wrong");
+///
+/// assert_eq!(capture.warnings(), ["something went wrong"]);
+/// assert_eq!(capture.finish(), [(TraceLogLevel::Warning, "something went wrong".to_string())]);
+/// ```
+#[derive(Debug)]
+pub struct TraceLogCapture {
+    id: u64
+}
+
+impl TraceLogCapture {
+    /// Starts collecting the messages logged by the current thread.
+    pub fn start() -> Self {
+        let id = NEXT_CAPTURE.fetch_add(1, Ordering::Relaxed);
+        lock(&CAPTURES).push((id, thread::current().id(), Vec::new()));
+        update_callback();
+        TraceLogCapture { id }
+    }
+
+    fn with_messages<R>(&self, f: impl FnOnce(&mut Messages) -> R) -> R {
+        let mut captures = lock(&CAPTURES);
+        let (_, _, messages) = captures.iter_mut().find(|(id, _, _)| *id == self.id).expect("a started capture");
+        f(messages)
+    }
+
+    /// The messages collected so far.
+    pub fn messages(&self) -> Vec<(TraceLogLevel, String)> {
+        self.with_messages(|messages| messages.clone())
+    }
+
+    /// The messages collected so far with a level of `Warning` or higher (`Warning`, `Error` and `Fatal`).
+    pub fn warnings(&self) -> Vec<String> {
+        self.with_messages(|messages| {
+            messages.iter()
+                .filter(|(level, _)| *level as i32 >= TraceLogLevel::Warning as i32)
+                .map(|(_, message)| message.clone())
+                .collect()
+        })
+    }
+
+    /// Removes the messages collected so far, and returns them.
+    pub fn take(&self) -> Vec<(TraceLogLevel, String)> {
+        self.with_messages(std::mem::take)
+    }
+
+    /// Stops collecting messages, and returns them.
+    pub fn finish(self) -> Vec<(TraceLogLevel, String)> {
+        self.take()
+    }
+}
+
+impl Drop for TraceLogCapture {
+    fn drop(&mut self) {
+        lock(&CAPTURES).retain(|(id, _, _)| *id != self.id);
+        update_callback();
+    }
+}
+
+/// Runs `f`, collecting the messages raylib logs in the meantime.
+/// Warnings are collected even if the trace log level is higher.
+/// The messages are logged normally afterwards.
+pub(crate) fn capture_trace_log<R>(f: impl FnOnce() -> R) -> (R, Vec<(TraceLogLevel, String)>) {
+    let level = TRACE_LOG_LEVEL.load(Ordering::Relaxed);
+    let lowered = level > TraceLogLevel::Warning as i32;
+    if lowered { unsafe { ffi::SetTraceLogLevel(TraceLogLevel::Warning as i32) } }
+
+    let capture = TraceLogCapture::start();
+    let result = f();
+    let messages = capture.finish();
+
+    if lowered { unsafe { ffi::SetTraceLogLevel(level) } }
+
+    for (level, message) in &messages {
+        if let Ok(message) = CString::new(message.as_str()) {
+            unsafe { ffi::TraceLog(*level as i32, cstr!("%s").as_ptr(), message.as_ptr()) }
+        }
+    }
+    (result, messages)
+}
@@ -6,11 +6,25 @@ pub mod vr;
 pub mod automation;
 pub mod input;
 pub mod other;
+pub mod logging;
+pub mod vfs;
+pub mod pack;
+pub mod assets;
+pub mod app;
+#[cfg(feature = "text")]
 mod default_font;
+#[cfg(feature = "raw-window-handle")]
+mod window_handle;
+#[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+mod headless;
 
-use std::{ffi::c_void, mem::ManuallyDrop, ptr::NonNull};
+use std::{ffi::c_void, ptr::NonNull};
+#[cfg(feature = "text")]
+use std::mem::ManuallyDrop;
 
-use crate::{ffi, text::bitmap::BitmapFontAtlas};
+use crate::ffi;
+#[cfg(feature = "text")]
+use crate::text::bitmap::BitmapFontAtlas;
 
 /// A handle to raylib's internal state.
 /// 
@@ -32,6 +46,7 @@ pub struct Raylib {
     quit_requested: bool,
     /// Keeps hold of the default raylib font.
     /// NOTE: The default font needs to be dropped before raylib itself closes, which is why the `ManuallyDrop` is needed.
+    #[cfg(feature = "text")]
     default_font: ManuallyDrop<Option<BitmapFontAtlas>>,
     // disallows initialization from outside and makes raylib !Send and !Sync
     _private: std::marker::PhantomData<*const c_void>
@@ -40,6 +55,7 @@ pub struct Raylib {
 impl Drop for Raylib {
     fn drop(&mut self) {
         // Drop default font (and free texture) before raylib exits
+        #[cfg(feature = "text")]
         unsafe { ManuallyDrop::drop(&mut self.default_font) }
         unsafe { ffi::CloseWindow() }
     }
@@ -1,19 +1,32 @@
-use std::{ffi::{CStr, CString}, ptr::NonNull, sync::Mutex};
+use std::{ffi::{CStr, CString}, ptr::NonNull};
 
-use crate::{core::Raylib, ffi::{self, TraceLogLevel}};
+use crate::{core::Raylib, cstr, ffi::{self, TraceLogLevel}, Error};
 
 use super::RaylibAlloc;
 
 /// Sets the maximum FPS
 pub fn set_target_fps(_: &Raylib, fps: i32) {
+    #[cfg(feature = "custom_frame_control")]
+    frame_control::TARGET_FRAME_TIME.store(if fps > 0 { 1.0 / fps as f64 } else { 0.0 }.to_bits(), std::sync::atomic::Ordering::Relaxed);
     unsafe { ffi::SetTargetFPS(fps) }
 }
 
 /// Gets the time elapsed in seconds since the last frame was drawn (delta time)
 pub fn get_frame_time(_: &Raylib) -> f32 {
+    #[cfg(feature = "custom_frame_control")]
+    return f64::from_bits(frame_control::FRAME_TIME.load(std::sync::atomic::Ordering::Relaxed)) as f32;
+    #[cfg(not(feature = "custom_frame_control"))]
     unsafe { ffi::GetFrameTime() }
 }
 
+/// Gets the current FPS, averaged over the last frames
+pub fn get_fps(_: &Raylib) -> i32 {
+    #[cfg(feature = "custom_frame_control")]
+    return frame_control::fps();
+    #[cfg(not(feature = "custom_frame_control"))]
+    unsafe { ffi::GetFPS() }
+}
+
 /// Gets the time elapsed in seconds since `Raylib::init_window` was called 
 /// If you need greater precision, use `Raylib::get_time_f64`
 pub fn get_time(rl: &Raylib) -> f32 {
@@ -36,21 +49,75 @@ pub fn wait_time_f64(_: &Raylib, seconds: f64) {
     unsafe { ffi::WaitTime(seconds) }
 }
 
-// TODO:
-// // === Custom frame control functions ===
-// impl Raylib {
-//     /// Swap back buffer with front buffer (show what was drawn to the screen)
-//     /// NOTE: Frame control functions are intented for advenced users that want full control over the frame processing
-//     /// By default EndDrawing() does this job: draws everything + SwapScreenBuffer() + manage frame timing + PollInputEvents()
-//     /// To avoid that behaviour and control frame processes manually, enable in config.h: SUPPORT_CUSTOM_FRAME_CONTROL
-//     pub fn swap_scren_buffer(&self) {
-//         unsafe { ffi::SwapScreenBuffer() }
-//     }
-//
-//     pub fn poll_input_events(&self) {
-//         unsafe { ffi::PollInputEvents() }
-//     }
-// }
+/// Swap back buffer with front buffer (show what was drawn to the screen)
+///
+/// Frame control functions are intended for advanced users that want full control over the frame processing.
+/// By default, ending drawing does this job: draws everything + [`swap_screen_buffer`] + manage frame timing + [`poll_input_events`].
+/// To control frame processes manually, draw with [`begin_drawing_manual`](super::draw::begin_drawing_manual).
+#[cfg(feature = "custom_frame_control")]
+pub fn swap_screen_buffer(_: &mut Raylib) {
+    unsafe { ffi::SwapScreenBuffer() }
+}
+
+/// Register all input events (keyboard, mouse, gamepads, window events, ...)
+///
+/// See [`swap_screen_buffer`].
+#[cfg(feature = "custom_frame_control")]
+pub fn poll_input_events(_: &mut Raylib) {
+    unsafe { ffi::PollInputEvents() }
+}
+
+/// Raylib compiled with `SUPPORT_CUSTOM_FRAME_CONTROL` leaves the end of the frame to the user,
+/// so it is done here for [`begin_drawing`](super::draw::begin_drawing).
+#[cfg(feature = "custom_frame_control")]
+pub(crate) mod frame_control {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::{get_time_f64, poll_input_events, swap_screen_buffer, wait_time_f64, Raylib};
+
+    /// Bits of a `f64`, in seconds (0 for unlimited).
+    pub(crate) static TARGET_FRAME_TIME: AtomicU64 = AtomicU64::new(0);
+    /// Bits of a `f64`, in seconds.
+    pub(crate) static FRAME_TIME: AtomicU64 = AtomicU64::new(0);
+    static PREVIOUS_FRAME: AtomicU64 = AtomicU64::new(0);
+    /// Bits of a `f64`, moving average of the frame time in seconds (raylib's `GetFPS` is compiled out).
+    static AVERAGE_FRAME_TIME: AtomicU64 = AtomicU64::new(0);
+
+    /// Starts timing from zero when a window is created, since raylib's timer restarts (the target FPS is kept, like in raylib).
+    pub(crate) fn reset() {
+        for time in [&FRAME_TIME, &PREVIOUS_FRAME, &AVERAGE_FRAME_TIME] {
+            time.store(0.0f64.to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    /// Frames per second, from the average frame time.
+    pub(crate) fn fps() -> i32 {
+        let average = f64::from_bits(AVERAGE_FRAME_TIME.load(Ordering::Relaxed));
+        if average > 0.0 { (1.0 / average).round() as i32 } else { 0 }
+    }
+
+    /// Shows the frame, waits to keep the target FPS, and polls the events, like `EndDrawing` does normally.
+    pub(crate) fn end_frame(rl: &mut Raylib) {
+        swap_screen_buffer(rl);
+
+        let target = f64::from_bits(TARGET_FRAME_TIME.load(Ordering::Relaxed));
+        let previous = f64::from_bits(PREVIOUS_FRAME.load(Ordering::Relaxed));
+        let mut now = get_time_f64(rl);
+        if now - previous < target {
+            wait_time_f64(rl, target - (now - previous));
+            now = get_time_f64(rl);
+        }
+        let frame_time = now - previous;
+        FRAME_TIME.store(frame_time.to_bits(), Ordering::Relaxed);
+        PREVIOUS_FRAME.store(now.to_bits(), Ordering::Relaxed);
+
+        let average = f64::from_bits(AVERAGE_FRAME_TIME.load(Ordering::Relaxed));
+        let average = if average > 0.0 { average * 0.9 + frame_time * 0.1 } else { frame_time };
+        AVERAGE_FRAME_TIME.store(average.to_bits(), Ordering::Relaxed);
+
+        poll_input_events(rl);
+    }
+}
 
 pub struct RandomSequence<'a>(&'a mut [i32]);
 
@@ -136,54 +203,60 @@ pub fn trace_log(level: TraceLogLevel, text: &str) {
 
 /// Show a log message of the given level
 pub fn trace_log_cstr(level: TraceLogLevel, text: &CStr) {
-    unsafe { ffi::TraceLog(level as i32, text.as_ptr()) }
+    // the text is not used as the format string, so that `%` is printed as is
+    unsafe { ffi::TraceLog(level as i32, cstr!("%s").as_ptr(), text.as_ptr()) }
 }
 
 /// Sets the minimum log level (log message under this level won't be printed)
 pub fn set_trace_log_level(level: TraceLogLevel) {
+    super::logging::TRACE_LOG_LEVEL.store(level as i32, std::sync::atomic::Ordering::Relaxed);
     unsafe { ffi::SetTraceLogLevel(level as i32) }
 }
 
-static CUSTOM_CALLBACK: Mutex<Option<fn(TraceLogLevel, &str, va_list::VaList)>> = Mutex::new(None);
-
-extern "C" fn internal_trace_log_callback(level: i32, msg: *const i8, va: va_list::VaList) {
-    let callback = CUSTOM_CALLBACK.lock().unwrap();
-    let callback = callback.unwrap();
-
-    let msg = unsafe { CStr::from_ptr(msg) }.to_str().unwrap();
-
-    callback(level.try_into().unwrap(), msg, va);
-}
-
+/// Sets a callback receiving raylib's log messages instead of printing them.
+/// The message is an unformatted printf-style string, with its arguments in the `va_list`.
+///
+/// Use [`set_trace_log_handler`](super::logging::set_trace_log_handler) to get formatted messages instead.
 pub fn set_trace_log_callback(callback: fn(TraceLogLevel, &str, va_list::VaList)) {
-    let mut lock = CUSTOM_CALLBACK.lock().unwrap();
-    *lock = Some(callback);
-    drop(lock);
-    unsafe {
-        ffi::SetTraceLogCallback(internal_trace_log_callback)
-    }
+    super::logging::set_raw_callback(callback)
 }
 
 /// Compress data using the DEFLATE algorithm
-/// # Panics
-/// This function panics if raylib was not compiled with support for the compression api
-pub fn compress_data(data: &[u8]) -> RaylibAlloc<[u8]> {
+///
+/// Returns [`Error::UnsupportedFormat`] if raylib was not compiled with support for the compression api (the `compression` feature).
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let data = "hello ".repeat(100);
+/// let compressed = compress_data(data.as_bytes()).unwrap();
+/// assert!(compressed.len() < data.len());
+/// assert_eq!(&*decompress_data(&compressed).unwrap(), data.as_bytes());
+/// ```
+pub fn compress_data(data: &[u8]) -> Result<RaylibAlloc<[u8]>, Error> {
+    if !cfg!(feature = "compression") { return Err(Error::UnsupportedFormat { feature: "compression" }) }
+
     let mut comp_data_size: i32 = 0;
     let compressed = unsafe { ffi::CompressData(data.as_ptr(), data.len() as i32, &mut comp_data_size as *mut i32) };
     let slice = std::ptr::slice_from_raw_parts_mut(compressed, comp_data_size as usize);
 
-    RaylibAlloc(NonNull::new(slice).expect("expected a valid pointer returned by CompressData"))
+    NonNull::new(slice).map(RaylibAlloc).ok_or(Error::InvalidData)
 }
 
 /// Decompress data compressed using the DEFLATE algorithm
-/// # Panics
-/// This function panics if raylib was not compiled with support for the compression api
-pub fn decompress_data(compressed: &[u8]) -> RaylibAlloc<[u8]> {
+///
+/// Returns [`Error::UnsupportedFormat`] if raylib was not compiled with support for the compression api (the `compression` feature),
+/// and [`Error::InvalidData`] if the data couldn't be decompressed.
+pub fn decompress_data(compressed: &[u8]) -> Result<RaylibAlloc<[u8]>, Error> {
+    if !cfg!(feature = "compression") { return Err(Error::UnsupportedFormat { feature: "compression" }) }
+    if compressed.is_empty() { return Err(Error::InvalidData) }
+
     let mut data_size: i32 = 0;
     let data = unsafe { ffi::DecompressData(compressed.as_ptr(), compressed.len() as i32, &mut data_size as *mut i32) };
 
-    let slice = std::ptr::slice_from_raw_parts_mut(data, data_size as usize);
-    RaylibAlloc(NonNull::new(slice).unwrap())
+    let slice = std::ptr::slice_from_raw_parts_mut(data, data_size.max(0) as usize);
+    let data = NonNull::new(slice).map(RaylibAlloc).ok_or(Error::InvalidData)?;
+    if data_size <= 0 { return Err(Error::InvalidData) }
+    Ok(data)
 }
 
 /// Encode data into a base 64 string
@@ -196,10 +269,16 @@ pub fn encode_data_base64(data: &[u8]) -> RaylibAlloc<[u8]> {
 }
 
 /// Decode data from a base 64 string
-pub fn decode_data_base64(compressed: &[u8]) -> RaylibAlloc<[u8]> {
+///
+/// Returns [`Error::InvalidData`] if the data couldn't be decoded.
+pub fn decode_data_base64(encoded: &[u8]) -> Result<RaylibAlloc<[u8]>, Error> {
+    // raylib reads the string up to a null terminator, 4 characters at a time
+    if !encoded.len().is_multiple_of(4) { return Err(Error::InvalidData) }
+    let encoded = CString::new(encoded)?;
+
     let mut data_size: i32 = 0;
-    let data = unsafe { ffi::DecodeDataBase64(compressed.as_ptr(), &mut data_size as *mut i32) };
+    let data = unsafe { ffi::DecodeDataBase64(encoded.as_ptr() as *const u8, &mut data_size as *mut i32) };
 
-    let slice = std::ptr::slice_from_raw_parts_mut(data, data_size as usize);
-    RaylibAlloc(NonNull::new(slice).unwrap())
+    let slice = std::ptr::slice_from_raw_parts_mut(data, data_size.max(0) as usize);
+    NonNull::new(slice).map(RaylibAlloc).ok_or(Error::InvalidData)
 }
@@ -0,0 +1,311 @@
+//! Asset packs: many files bundled in a single archive, optionally compressed.
+//!
+//! Packs are made with an [`AssetPackBuilder`] (usually in a build script),
+//! and read at runtime with an [`AssetPack`], either embedded in the executable with `include_bytes!` or opened from the disk.
+//! Installing a pack with [`set_vfs`](super::vfs::set_vfs) makes every loader of the crate (textures, models, shaders, fonts, sounds, ...)
+//! accept paths inside of the pack.
+//!
+//! # Format
+//! All integers are little endian.
+//! ```text
+//! header:
+//!     magic         [u8; 4]   "RLPK"
+//!     version       u16       1
+//!     entry count   u32
+//! entries (entry count times):
+//!     path length   u16
+//!     path          [u8]      normalized UTF-8 path (see `normalize_path`)
+//!     flags         u8        bit 0: the data is compressed
+//!     offset        u64       offset of the data from the start of the pack
+//!     stored size   u64       size of the data in the pack
+//!     size          u64       size of the data once decompressed
+//! data:
+//!     the content of each file, compressed with DEFLATE (raylib's `CompressData`) if the flag is set
+//! ```
+//!
+//! # Example
+//! In `build.rs` (with `raylib-bindings` in the `[build-dependencies]`):
+//! ```
+//! # use raylib::prelude::*;
+//! # fn main() -> std::io::Result<()> {
+//! # if std::env::var_os("OUT_DIR").is_none() { return Ok(()) }
+//! let mut pack = AssetPackBuilder::new();
+//! pack.add_directory("", "assets")?;
+//! pack.write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("assets.pack"))?;
+//! println!("cargo:rerun-if-changed=assets");
+//! # Ok(())
+//! # }
+//! ```
+//! Then in the game:
+//! ```
+//! # use raylib::prelude::*;
+//! # let mut builder = AssetPackBuilder::new();
+//! # builder.add_directory("", "assets").unwrap();
+//! # let assets: &'static [u8] = builder.build().leak();
+//! // let assets: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), "/assets.pack"));
+//! let rl = &mut init_window(800, 800, "Packed", 60);
+//! set_vfs(AssetPack::new(assets).unwrap());
+//! let font = load_font(rl, "TerminusTTF.ttf").unwrap();
+//! # clear_vfs();
+//! ```
+
+use std::{borrow::Cow, collections::{BTreeMap, HashMap}, io, path::Path};
+
+use super::{other::{compress_data, decompress_data}, vfs::{normalize_path, Vfs}};
+
+const MAGIC: &[u8; 4] = b"RLPK";
+const VERSION: u16 = 1;
+const FLAG_COMPRESSED: u8 = 1;
+/// Largest file raylib can decompress (`MAX_DECOMPRESSION_SIZE`), bigger files are stored uncompressed.
+const MAX_COMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+/// Builds an asset pack (see the [module documentation](self) for the format).
+///
+/// Files are compressed when it makes them smaller, unless compression is disabled with [`AssetPackBuilder::set_compression`].
+/// Building a pack doesn't need a window, so it can be done in a build script.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let mut builder = AssetPackBuilder::new();
+/// builder.add("levels/1.txt", "#####\n#@ $#\n#####".repeat(10)).unwrap();
+/// builder.add_file("sounds/coin.wav", "assets/coin.wav").unwrap();
+///
+/// let pack = AssetPack::new(builder.build()).unwrap();
+/// assert_eq!(pack.read("levels/1.txt").unwrap(), "#####\n#@ $#\n#####".repeat(10).as_bytes());
+/// assert_eq!(pack.read("sounds/coin.wav").unwrap(), std::fs::read("assets/coin.wav").unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssetPackBuilder {
+    files: BTreeMap<String, Vec<u8>>,
+    compression: bool
+}
+
+impl Default for AssetPackBuilder {
+    fn default() -> Self {
+        AssetPackBuilder::new()
+    }
+}
+
+impl AssetPackBuilder {
+    pub fn new() -> Self {
+        AssetPackBuilder { files: BTreeMap::new(), compression: true }
+    }
+
+    /// Enables or disables compression (enabled by default).
+    pub fn set_compression(&mut self, compression: bool) {
+        self.compression = compression;
+    }
+
+    /// Adds a file to the pack, replacing any file with the same path.
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if the path goes above the root of the pack,
+    /// isn't valid UTF-8, or is longer than 65535 bytes.
+    pub fn add(&mut self, path: impl AsRef<Path>, data: impl Into<Vec<u8>>) -> io::Result<()> {
+        let path = normalize_path(path.as_ref())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "asset pack paths must be UTF-8 and inside of the pack"))?;
+        if path.len() > u16::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "asset pack paths can't be longer than 65535 bytes"))
+        }
+        self.files.insert(path, data.into());
+        Ok(())
+    }
+
+    /// Adds a file from the disk, at `path` in the pack.
+    pub fn add_file(&mut self, path: impl AsRef<Path>, file: impl AsRef<Path>) -> io::Result<()> {
+        let data = std::fs::read(file)?;
+        self.add(path, data)
+    }
+
+    /// Adds every file in a directory (recursively), under `prefix` in the pack.
+    /// Use an empty prefix to put them at the root of the pack.
+    pub fn add_directory(&mut self, prefix: impl AsRef<Path>, directory: impl AsRef<Path>) -> io::Result<()> {
+        for entry in std::fs::read_dir(directory)? {
+            let entry = entry?;
+            let path = prefix.as_ref().join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                self.add_directory(path, entry.path())?;
+            } else {
+                self.add_file(path, entry.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Normalized paths of the files added so far.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(|path| path.as_str())
+    }
+
+    /// Builds the pack.
+    /// The output only depends on the files added, so rebuilding the same files gives the same pack.
+    pub fn build(&self) -> Vec<u8> {
+        let blobs: Vec<(&str, Cow<[u8]>, bool, usize)> = self.files.iter().map(|(path, data)| {
+            if self.compression && !data.is_empty() && data.len() <= MAX_COMPRESSED_SIZE {
+                // stored uncompressed when the compression api isn't compiled in
+                if let Ok(compressed) = compress_data(data) {
+                    if compressed.len() < data.len() {
+                        return (path.as_str(), Cow::Owned(compressed.to_vec()), true, data.len())
+                    }
+                }
+            }
+            (path.as_str(), Cow::Borrowed(data.as_slice()), false, data.len())
+        }).collect();
+
+        let index_size: usize = blobs.iter().map(|(path, ..)| 2 + path.len() + 1 + 3 * 8).sum();
+        let mut offset = (MAGIC.len() + 2 + 4 + index_size) as u64;
+
+        let mut pack = Vec::with_capacity(offset as usize + blobs.iter().map(|(_, data, ..)| data.len()).sum::<usize>());
+        pack.extend_from_slice(MAGIC);
+        pack.extend_from_slice(&VERSION.to_le_bytes());
+        pack.extend_from_slice(&(blobs.len() as u32).to_le_bytes());
+
+        for (path, data, compressed, size) in &blobs {
+            pack.extend_from_slice(&(path.len() as u16).to_le_bytes());
+            pack.extend_from_slice(path.as_bytes());
+            pack.push(if *compressed { FLAG_COMPRESSED } else { 0 });
+            pack.extend_from_slice(&offset.to_le_bytes());
+            pack.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            pack.extend_from_slice(&(*size as u64).to_le_bytes());
+            offset += data.len() as u64;
+        }
+
+        for (_, data, ..) in &blobs {
+            pack.extend_from_slice(data);
+        }
+        pack
+    }
+
+    /// Builds the pack and writes it to a file.
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.build())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    offset: usize,
+    stored_size: usize,
+    size: usize,
+    compressed: bool
+}
+
+/// A pack of assets made by an [`AssetPackBuilder`].
+///
+/// It implements [`Vfs`], so it can be installed with [`set_vfs`](super::vfs::set_vfs) to load assets from it.
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct AssetPack {
+    data: Cow<'static, [u8]>,
+    entries: HashMap<String, Entry>
+}
+
+impl AssetPack {
+    /// Reads the index of a pack.
+    /// Use a `&'static [u8]` (from `include_bytes!`) to avoid copying an embedded pack.
+    ///
+    /// Returns [`io::ErrorKind::InvalidData`] if the data isn't a valid pack.
+    pub fn new(data: impl Into<Cow<'static, [u8]>>) -> io::Result<Self> {
+        let data = data.into();
+        let mut reader = Reader { data: &data, position: 0 };
+
+        if reader.take(MAGIC.len())? != MAGIC { return Err(invalid("not an asset pack")) }
+        let version = u16::from_le_bytes(reader.array()?);
+        if version != VERSION { return Err(invalid("unsupported asset pack version")) }
+
+        let count = u32::from_le_bytes(reader.array()?);
+        let mut entries = HashMap::new();
+        for _ in 0..count {
+            let length = u16::from_le_bytes(reader.array()?) as usize;
+            let path = std::str::from_utf8(reader.take(length)?).map_err(|_| invalid("invalid path in asset pack"))?;
+            let [flags] = reader.array()?;
+
+            let mut read_size = || usize::try_from(u64::from_le_bytes(reader.array()?)).map_err(|_| invalid("asset pack entry too large"));
+            let entry = Entry { offset: read_size()?, stored_size: read_size()?, size: read_size()?, compressed: flags & FLAG_COMPRESSED != 0 };
+
+            let end = entry.offset.checked_add(entry.stored_size);
+            if end.is_none_or(|end| end > data.len()) { return Err(invalid("asset pack entry out of bounds")) }
+            if entry.compressed && (entry.size == 0 || entry.size > MAX_COMPRESSED_SIZE) {
+                return Err(invalid("invalid compressed asset pack entry"))
+            }
+            if !entry.compressed && entry.size != entry.stored_size {
+                return Err(invalid("invalid asset pack entry size"))
+            }
+
+            entries.insert(path.to_owned(), entry);
+        }
+
+        Ok(AssetPack { data, entries })
+    }
+
+    /// Reads a pack from the disk.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        AssetPack::new(std::fs::read(path)?)
+    }
+
+    /// Normalized paths of every file in the pack.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(|path| path.as_str())
+    }
+
+    /// Number of files in the pack.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.entry(path.as_ref()).is_some()
+    }
+
+    /// Reads the content of a file, decompressing it if needed.
+    /// Uncompressed files are borrowed from the pack.
+    ///
+    /// Returns [`io::ErrorKind::NotFound`] if the file isn't in the pack,
+    /// and [`io::ErrorKind::InvalidData`] if it couldn't be decompressed.
+    pub fn read(&self, path: impl AsRef<Path>) -> io::Result<Cow<'_, [u8]>> {
+        let entry = self.entry(path.as_ref()).ok_or(io::ErrorKind::NotFound)?;
+        let stored = &self.data[entry.offset..entry.offset + entry.stored_size];
+        if !entry.compressed { return Ok(Cow::Borrowed(stored)) }
+
+        let data = decompress_data(stored).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if data.len() != entry.size { return Err(invalid("corrupted asset pack entry")) }
+        Ok(Cow::Owned(data.to_vec()))
+    }
+
+    fn entry(&self, path: &Path) -> Option<&Entry> {
+        self.entries.get(&normalize_path(path)?)
+    }
+}
+
+impl Vfs for AssetPack {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        AssetPack::read(self, path).map(Cow::into_owned)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.contains(path)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> io::Result<&'a [u8]> {
+        let bytes = self.data.get(self.position..self.position + count).ok_or_else(|| invalid("truncated asset pack"))?;
+        self.position += count;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+}
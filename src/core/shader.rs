@@ -1,5 +1,9 @@
 use std::{ffi::{CString, CStr, c_void}, ptr::null};
-use crate::{ffi::{self, Matrix, ShaderUniformDataType}, prelude::{Raylib, Texture, Vector2, Vector3, Vector4, WeakTexture}};
+use crate::{ffi::{self, Matrix, ShaderUniformDataType, TraceLogLevel}, prelude::{Raylib, Vector2, Vector3, Vector4}, Error};
+#[cfg(feature = "textures")]
+use crate::prelude::{Texture, WeakTexture};
+
+use super::logging::capture_trace_log;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Uniform(i32);
@@ -10,10 +14,8 @@ pub struct Shader(ffi::Shader);
 impl Shader {
     /// Reads code for a shader from the given files, and loads them into raylib.
     /// The default shader is used for `None`s.
-    /// Returns an error if there is a problem with reading the given files.
-    /// 
-    /// # Panics
-    /// Panics if the given files contain null bytes in them.
+    /// Returns an error if there is a problem with reading the given files,
+    /// if they contain null bytes, or if the shader fails to compile.
     /// 
     /// # Example
     /// ```
@@ -32,27 +34,50 @@ impl Shader {
     ///     # break
     /// }
     /// ```
-    pub fn load<P: AsRef<std::path::Path>>(rl: &mut Raylib, vs_file_name: Option<P>, fs_file_name: Option<P>) -> std::io::Result<Self> {
-        let vs = vs_file_name.map(std::fs::read).transpose()?;
-        let fs = fs_file_name.map(std::fs::read).transpose()?;
+    pub fn load<P: AsRef<std::path::Path>>(rl: &mut Raylib, vs_file_name: Option<P>, fs_file_name: Option<P>) -> Result<Self, Error> {
+        let vs = vs_file_name.map(super::vfs::read).transpose()?;
+        let fs = fs_file_name.map(super::vfs::read).transpose()?;
 
-        let vs = vs.map(CString::new).transpose().expect("a vertex shader file without nulls");
-        let fs = fs.map(CString::new).transpose().expect("a fragment shader file without nulls");
+        let vs = vs.map(CString::new).transpose()?;
+        let fs = fs.map(CString::new).transpose()?;
 
-        Ok(Self::load_from_memory(rl, vs.as_deref(), fs.as_deref()))
+        Self::load_from_memory(rl, vs.as_deref(), fs.as_deref())
     }
 
     /// Loads the code for a shader from the given CStrings
     /// The default shader is used for `None`s.
-    pub fn load_from_memory(_: &mut Raylib, vs_code: Option<&CStr>, fs_code: Option<&CStr>) -> Self {
-        let shader = unsafe {
+    ///
+    /// Returns [`Error::ShaderCompile`] with the compilation log if the shader fails to compile.
+    /// # Example
+    /// ```
+    /// # use raylib::prelude::*;
+    /// # use std::ffi::CStr;
+    /// # let rl = &mut init_window(500, 500, "shader test", 60);
+    /// let code = raylib::cstr!("#version 330\nvoid main() {\n    syntax error\n}");
+    /// match Shader::load_from_memory(rl, None, Some(code)) {
+    ///     Err(raylib::Error::ShaderCompile(e)) => {
+    ///         assert_eq!(e.stage, ShaderStage::Fragment);
+    ///         assert!(e.lines().any(|line| line == 3));
+    ///     }
+    ///     _ => panic!("the shader shouldn't compile")
+    /// }
+    /// ```
+    pub fn load_from_memory(_: &mut Raylib, vs_code: Option<&CStr>, fs_code: Option<&CStr>) -> Result<Self, Error> {
+        let (shader, messages) = capture_trace_log(|| unsafe {
             ffi::LoadShaderFromMemory(
                 vs_code.map_or(null(), |s| s.as_ptr()),
                 fs_code.map_or(null(), |s| s.as_ptr())
             )
-        };
+        });
+        let shader = Self(shader);
 
-        Self(shader)
+        // raylib falls back to the default shader when compilation fails
+        let is_default = shader.0.id == unsafe { crate::rlgl::rlGetShaderIdDefault() };
+        if is_default && (vs_code.is_some() || fs_code.is_some()) {
+            return Err(ShaderError::from_messages(&messages).into())
+        }
+
+        Ok(shader)
     }
 
     pub fn is_ready(&self) -> bool {
@@ -123,6 +148,122 @@ impl Drop for Shader {
     }
 }
 
+/// A step of building a shader program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderStage {
+    /// Compiling the vertex shader.
+    Vertex,
+    /// Compiling the fragment shader.
+    Fragment,
+    /// Linking the compiled shaders into a program.
+    Link
+}
+
+impl std::fmt::Display for ShaderStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderStage::Vertex => write!(f, "vertex shader"),
+            ShaderStage::Fragment => write!(f, "fragment shader"),
+            ShaderStage::Link => write!(f, "shader program")
+        }
+    }
+}
+
+/// A line of a shader's info log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderDiagnostic {
+    /// The line of the shader source the message refers to, if the driver gave one.
+    pub line: Option<u32>,
+    pub message: String
+}
+
+/// A shader that failed to compile or link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderError {
+    /// The first stage that failed.
+    pub stage: ShaderStage,
+    /// The info log given by the driver for this stage.
+    pub log: String,
+    /// The lines of the log, with the source line numbers they refer to.
+    /// The format of the log depends on the driver, lines in an unknown format have no line number.
+    pub diagnostics: Vec<ShaderDiagnostic>
+}
+
+impl ShaderError {
+    /// Rebuilds the error from what raylib logged while loading the shader.
+    fn from_messages(messages: &[(TraceLogLevel, String)]) -> Self {
+        let mut stage = None;
+        let mut log = String::new();
+        for (level, message) in messages {
+            if *level as i32 != TraceLogLevel::Warning as i32 { continue }
+            let Some(message) = message.strip_prefix("SHADER: ") else { continue };
+
+            let failed = if message.contains("Failed to compile vertex shader") { Some(ShaderStage::Vertex) }
+                else if message.contains("Failed to compile fragment shader") { Some(ShaderStage::Fragment) }
+                else if message.contains("Failed to link shader program") { Some(ShaderStage::Link) }
+                else { None };
+
+            match (failed, stage) {
+                // only keep the log of the first stage that failed, linking fails after a compilation error
+                (Some(failed), None) => stage = Some(failed),
+                (Some(_), Some(_)) => break,
+                (None, Some(_)) => if let Some((_, error)) = message.split_once(" error: ") {
+                    log.push_str(error.trim_end());
+                },
+                (None, None) => ()
+            }
+        }
+
+        let diagnostics = log.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| ShaderDiagnostic { line: parse_line_number(line), message: line.trim().to_string() })
+            .collect();
+        ShaderError { stage: stage.unwrap_or(ShaderStage::Link), log, diagnostics }
+    }
+
+    /// Source line numbers the log refers to.
+    pub fn lines(&self) -> impl Iterator<Item = u32> + '_ {
+        self.diagnostics.iter().filter_map(|diagnostic| diagnostic.line)
+    }
+}
+
+impl std::fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.stage == ShaderStage::Link { write!(f, "failed to link shader program")? }
+        else { write!(f, "failed to compile {}", self.stage)? }
+
+        if !self.log.is_empty() { write!(f, ":\n{}", self.log)? }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+/// Finds the source line number in a line of an info log.
+///
+/// Handles the formats used by the common drivers:
+/// - `0:12(5): error: ...` (mesa)
+/// - `ERROR: 0:12: ...` (amd, intel, apple)
+/// - `0(12) : error C0000: ...` (nvidia)
+fn parse_line_number(line: &str) -> Option<u32> {
+    fn split_number(s: &str) -> Option<(u32, &str)> {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        Some((s[..end].parse().ok()?, &s[end..]))
+    }
+
+    let line = line.trim_start();
+    let line = ["ERROR:", "WARNING:"].iter().find_map(|prefix| line.strip_prefix(prefix)).unwrap_or(line).trim_start();
+
+    // the first number is the index of the source string
+    let (_, rest) = split_number(line)?;
+    if let Some(rest) = rest.strip_prefix(':') {
+        split_number(rest).map(|(number, _)| number)
+    } else if let Some(rest) = rest.strip_prefix('(') {
+        let (number, rest) = split_number(rest)?;
+        rest.starts_with(')').then_some(number)
+    } else { None }
+}
+
 /// Links a type with its shader representation.
 /// # Safety
 /// `DATA_TYPE` and `T` should have the same size and representation between cpu and shader.
@@ -203,12 +344,14 @@ impl ShaderValue<Matrix> for Shader {
     }
 }
 
+#[cfg(feature = "textures")]
 impl ShaderValue<&Texture> for Shader {
     fn set_uniform_value(&self, uniform: Uniform, v: &Texture) {
         unsafe { ffi::SetShaderValueTexture(self.0, uniform.0, *v.get_ffi()) }
     }
 }
 
+#[cfg(feature = "textures")]
 impl ShaderValue<WeakTexture> for Shader {
     fn set_uniform_value(&self, uniform: Uniform, v: WeakTexture) {
         unsafe { ffi::SetShaderValueTexture(self.0, uniform.0, *v.get_ffi()) }
@@ -0,0 +1,453 @@
+//! Virtual file system, to load assets from somewhere else than the disk.
+//!
+//! Once a [`Vfs`] is installed with [`set_vfs`], every file raylib reads or writes goes through it
+//! (models, materials and the textures they reference, images, shaders, sounds, ...),
+//! as well as the crate's own loaders that read files from Rust.
+//!
+//! Relative references inside of `.obj` and `.mtl` files (material libraries, textures)
+//! are resolved relative to the directory of the model.
+//! NOTE: raylib still tries to change the working directory to the model's directory,
+//! which fails with a warning if it doesn't exist on the disk. This warning is harmless.
+//!
+//! Material libraries are read through the VFS by a patch to the vendored raylib (see `patches/`).
+//! When linking an installed raylib (the `system` feature or `RAYLIB_SYS_DIR`), `.mtl` files are always read from the disk.
+//! # Example
+//! Load a model and its materials from memory:
+//! ```
+//! # use raylib::prelude::*;
+//! # let rl = &mut init_window(100, 100, "vfs", 60);
+//! let vfs = MemoryVfs::new();
+//! vfs.insert("models/cube.obj", b"mtllib cube.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl red\nf 1 2 3\n".to_vec());
+//! vfs.insert("models/cube.mtl", b"newmtl red\nKd 1 0 0\n".to_vec());
+//! set_vfs(vfs);
+//!
+//! let model = Model::load("models/cube.obj").unwrap();
+//! clear_vfs();
+//! ```
+
+use std::{borrow::Cow, collections::HashMap, ffi::{c_char, c_int, c_uchar, c_void, CStr}, io, ops::Range, path::{Component, Path, PathBuf}, sync::{Arc, Mutex, RwLock}};
+
+use crate::ffi;
+
+/// A source of files.
+///
+/// Paths are given as they were requested, and may be relative.
+/// Implementations that aren't backed by the disk can use [`normalize_path`] to compare them.
+pub trait Vfs: Send + Sync {
+    /// Reads the whole content of a file.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Writes the whole content of a file, replacing it if it exists.
+    /// Read-only file systems return an [`io::ErrorKind::PermissionDenied`] error (the default).
+    fn write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let _ = (path, data);
+        Err(io::ErrorKind::PermissionDenied.into())
+    }
+
+    /// Checks if a file exists.
+    fn exists(&self, path: &Path) -> bool {
+        self.read(path).is_ok()
+    }
+}
+
+/// Normalizes a path to a key used by in-memory file systems:
+/// `/` separated, without leading `./` or `/`, with `..` components resolved.
+///
+/// Returns `None` if the path goes above its root, or if it isn't valid UTF-8.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// assert_eq!(normalize_path("./models/../textures/wall.png".as_ref()).as_deref(), Some("textures/wall.png"));
+/// assert_eq!(normalize_path("../outside.png".as_ref()), None);
+/// ```
+pub fn normalize_path(path: &Path) -> Option<String> {
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => {
+                // paths coming from raylib may mix separators
+                components.extend(name.to_str()?.split('\\').filter(|s| !s.is_empty()));
+            }
+            Component::ParentDir => { components.pop()?; }
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => ()
+        }
+    }
+    Some(components.join("/"))
+}
+
+/// Files stored in memory, indexed by their normalized path (see [`normalize_path`]).
+/// Writes are stored in memory as well.
+#[derive(Debug, Default)]
+pub struct MemoryVfs {
+    files: RwLock<HashMap<String, Arc<[u8]>>>
+}
+
+impl MemoryVfs {
+    pub fn new() -> Self {
+        MemoryVfs::default()
+    }
+
+    /// Adds or replaces a file.
+    /// # Panics
+    /// Panics if the path goes above the root of the file system.
+    pub fn insert(&self, path: impl AsRef<Path>, data: impl Into<Arc<[u8]>>) {
+        let key = normalize_path(path.as_ref()).expect("a path inside of the file system");
+        self.files.write().unwrap_or_else(|e| e.into_inner()).insert(key, data.into());
+    }
+
+    /// Removes a file, returning its content.
+    pub fn remove(&self, path: impl AsRef<Path>) -> Option<Arc<[u8]>> {
+        let key = normalize_path(path.as_ref())?;
+        self.files.write().unwrap_or_else(|e| e.into_inner()).remove(&key)
+    }
+
+    /// Normalized paths of every file.
+    pub fn paths(&self) -> Vec<String> {
+        self.files.read().unwrap_or_else(|e| e.into_inner()).keys().cloned().collect()
+    }
+
+    fn get(&self, path: &Path) -> Option<Arc<[u8]>> {
+        let key = normalize_path(path)?;
+        self.files.read().unwrap_or_else(|e| e.into_inner()).get(&key).cloned()
+    }
+}
+
+impl Vfs for MemoryVfs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.get(path).map(|data| data.to_vec()).ok_or(io::ErrorKind::NotFound.into())
+    }
+
+    fn write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let key = normalize_path(path).ok_or(io::ErrorKind::InvalidInput)?;
+        self.files.write().unwrap_or_else(|e| e.into_inner()).insert(key, data.into());
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.get(path).is_some()
+    }
+}
+
+/// Files in a directory on the disk.
+/// Paths are relative to the directory, and can't go outside of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryVfs {
+    root: PathBuf
+}
+
+impl DirectoryVfs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DirectoryVfs { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn resolve(&self, path: &Path) -> io::Result<PathBuf> {
+        let relative = normalize_path(path).ok_or(io::ErrorKind::InvalidInput)?;
+        Ok(self.root.join(relative))
+    }
+}
+
+impl Vfs for DirectoryVfs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(self.resolve(path)?)
+    }
+
+    fn write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let path = self.resolve(path)?;
+        if let Some(parent) = path.parent() { std::fs::create_dir_all(parent)? }
+        std::fs::write(path, data)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.resolve(path).is_ok_and(|path| path.is_file())
+    }
+}
+
+/// Layers several file systems on top of each other.
+///
+/// Files are read from the first layer that has them, and written to the first layer.
+/// # Example
+/// Let loose files in a `mods` directory override the assets embedded in the executable:
+/// ```
+/// # use raylib::prelude::*;
+/// let embedded = MemoryVfs::new();
+/// embedded.insert("sounds/coin.wav", include_bytes!("../../assets/coin.wav").as_slice());
+/// set_vfs(Overlay::new().with(DirectoryVfs::new("mods")).with(embedded));
+/// # clear_vfs();
+/// ```
+#[derive(Default)]
+pub struct Overlay {
+    layers: Vec<Box<dyn Vfs>>
+}
+
+impl std::fmt::Debug for Overlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Overlay").field("layers", &self.layers.len()).finish()
+    }
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Overlay::default()
+    }
+
+    /// Adds a layer under the existing ones.
+    pub fn with(mut self, layer: impl Vfs + 'static) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+}
+
+impl Vfs for Overlay {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let mut error = io::Error::from(io::ErrorKind::NotFound);
+        for layer in &self.layers {
+            match layer.read(path) {
+                Ok(data) => return Ok(data),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => error = e
+            }
+        }
+        Err(error)
+    }
+
+    fn write(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        match self.layers.first() {
+            Some(layer) => layer.write(path, data),
+            None => Err(io::ErrorKind::PermissionDenied.into())
+        }
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.layers.iter().any(|layer| layer.exists(path))
+    }
+}
+
+/// A read-only `tar` archive (ustar format, uncompressed).
+///
+/// The archive can be embedded in the executable with `include_bytes!`, or read at runtime.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// fn load_house() -> Result<Model, raylib::Error> {
+///     // or `TarVfs::new(include_bytes!("assets.tar").as_slice())`
+///     set_vfs(TarVfs::new(std::fs::read("assets.tar")?)?);
+///     Model::load("models/house.obj")
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TarVfs {
+    data: Cow<'static, [u8]>,
+    /// Range of each file's content in the archive, by normalized path.
+    files: HashMap<String, Range<usize>>
+}
+
+impl TarVfs {
+    /// Parses the archive's index.
+    /// Returns [`io::ErrorKind::InvalidData`] if the data isn't a valid tar archive.
+    pub fn new(data: impl Into<Cow<'static, [u8]>>) -> io::Result<Self> {
+        let data = data.into();
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid tar archive");
+
+        let mut files = HashMap::new();
+        let mut offset = 0;
+        while offset + 512 <= data.len() {
+            let header = &data[offset..offset + 512];
+            // the archive ends with empty blocks
+            if header.iter().all(|&b| b == 0) { break }
+
+            let field = |range: Range<usize>| {
+                let field = &header[range];
+                let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+                std::str::from_utf8(&field[..end]).map_err(|_| invalid())
+            };
+
+            let size = usize::from_str_radix(field(124..136)?.trim(), 8).map_err(|_| invalid())?;
+            let start = offset + 512;
+            let end = start.checked_add(size).filter(|&end| end <= data.len()).ok_or_else(invalid)?;
+
+            // regular files only
+            if matches!(header[156], b'0' | 0) {
+                let name = field(0..100)?;
+                let prefix = if &header[257..262] == b"ustar" { field(345..500)? } else { "" };
+                let path = if prefix.is_empty() { name.to_owned() } else { format!("{prefix}/{name}") };
+
+                if let Some(key) = normalize_path(path.as_ref()) {
+                    files.insert(key, start..end);
+                }
+            }
+
+            offset = start + size.div_ceil(512) * 512;
+        }
+
+        Ok(TarVfs { data, files })
+    }
+
+    /// Normalized paths of every file in the archive.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(|path| path.as_str())
+    }
+
+    /// Borrows the content of a file, without copying it.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        let range = self.files.get(&normalize_path(path.as_ref())?)?;
+        Some(&self.data[range.clone()])
+    }
+}
+
+impl Vfs for TarVfs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.get(path).map(<[u8]>::to_vec).ok_or(io::ErrorKind::NotFound.into())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.get(path).is_some()
+    }
+}
+
+static VFS: RwLock<Option<Arc<dyn Vfs>>> = RwLock::new(None);
+
+/// Directory of the model being loaded, relative references are resolved from it.
+static BASE_DIRECTORY: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+#[allow(clashing_extern_declarations)]
+extern "C" {
+    // Same functions as in `ffi`, but allowing NULL to restore the default behavior.
+    #[link_name = "SetLoadFileDataCallback"]
+    fn SetLoadFileDataCallbackNullable(callback: Option<ffi::LoadFileDataCallback>);
+    #[link_name = "SetSaveFileDataCallback"]
+    fn SetSaveFileDataCallbackNullable(callback: Option<ffi::SaveFileDataCallback>);
+    #[link_name = "SetLoadFileTextCallback"]
+    fn SetLoadFileTextCallbackNullable(callback: Option<ffi::LoadFileTextCallback>);
+    #[link_name = "SetSaveFileTextCallback"]
+    fn SetSaveFileTextCallbackNullable(callback: Option<ffi::SaveFileTextCallback>);
+}
+
+/// Installs a virtual file system, used for every file raylib and this crate load or save.
+/// Replaces the previously installed one.
+pub fn set_vfs(vfs: impl Vfs + 'static) {
+    *VFS.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(vfs));
+    unsafe {
+        ffi::SetLoadFileDataCallback(load_file_data);
+        ffi::SetSaveFileDataCallback(save_file_data);
+        ffi::SetLoadFileTextCallback(load_file_text);
+        ffi::SetSaveFileTextCallback(save_file_text);
+    }
+}
+
+/// Removes the installed virtual file system, going back to the real file system.
+pub fn clear_vfs() {
+    unsafe {
+        SetLoadFileDataCallbackNullable(None);
+        SetSaveFileDataCallbackNullable(None);
+        SetLoadFileTextCallbackNullable(None);
+        SetSaveFileTextCallbackNullable(None);
+    }
+    *VFS.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Checks if a virtual file system is installed.
+pub fn has_vfs() -> bool {
+    VFS.read().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+fn current() -> Option<Arc<dyn Vfs>> {
+    VFS.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Reads a file through the installed virtual file system, or from the disk if there is none.
+pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    let path = path.as_ref();
+    let Some(vfs) = current() else { return std::fs::read(path) };
+
+    match vfs.read(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound && path.is_relative() => {
+            let base = BASE_DIRECTORY.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            match base {
+                Some(base) => vfs.read(&base.join(path)),
+                None => Err(e)
+            }
+        }
+        result => result
+    }
+}
+
+/// Writes a file through the installed virtual file system, or to the disk if there is none.
+pub fn write(path: impl AsRef<Path>, data: &[u8]) -> io::Result<()> {
+    match current() {
+        Some(vfs) => vfs.write(path.as_ref(), data),
+        None => std::fs::write(path, data)
+    }
+}
+
+/// Checks if a file exists in the installed virtual file system, or on the disk if there is none.
+pub fn exists(path: impl AsRef<Path>) -> bool {
+    match current() {
+        Some(vfs) => vfs.exists(path.as_ref()),
+        None => path.as_ref().is_file()
+    }
+}
+
+/// Runs `f` with relative paths resolved from the directory of `file` as a fallback.
+/// Used when loading files that reference other files (like `.obj` models).
+#[cfg(feature = "models")]
+pub(crate) fn with_base_directory<R>(file: &Path, f: impl FnOnce() -> R) -> R {
+    if !has_vfs() { return f() }
+
+    let previous = std::mem::replace(
+        &mut *BASE_DIRECTORY.lock().unwrap_or_else(|e| e.into_inner()),
+        file.parent().map(Path::to_path_buf)
+    );
+    let result = f();
+    *BASE_DIRECTORY.lock().unwrap_or_else(|e| e.into_inner()) = previous;
+    result
+}
+
+fn c_path<'a>(path: *const c_char) -> Option<&'a Path> {
+    if path.is_null() { return None }
+    unsafe { CStr::from_ptr(path) }.to_str().ok().map(Path::new)
+}
+
+/// Copies data into a buffer allocated by raylib (which raylib frees itself).
+fn raylib_alloc(data: &[u8], null_terminated: bool) -> *mut u8 {
+    let size = data.len() + null_terminated as usize;
+    let ptr = unsafe { ffi::MemAlloc(size as u32) } as *mut u8;
+    if !ptr.is_null() {
+        // SAFETY: the buffer is at least `data.len()` bytes long, and zeroed (MemAlloc uses calloc)
+        unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len()) }
+    }
+    ptr
+}
+
+extern "C" fn load_file_data(path: *const c_char, size: *mut c_int) -> *mut c_uchar {
+    unsafe { *size = 0 }
+    let Some(data) = c_path(path).and_then(|path| read(path).ok()) else { return std::ptr::null_mut() };
+    if data.is_empty() || data.len() > c_int::MAX as usize { return std::ptr::null_mut() }
+
+    let ptr = raylib_alloc(&data, false);
+    if !ptr.is_null() { unsafe { *size = data.len() as c_int } }
+    ptr
+}
+
+extern "C" fn save_file_data(path: *const c_char, data: *mut c_void, size: c_int) -> bool {
+    let Some(path) = c_path(path) else { return false };
+    if data.is_null() || size < 0 { return false }
+
+    let data = unsafe { std::slice::from_raw_parts(data as *const u8, size as usize) };
+    write(path, data).is_ok()
+}
+
+extern "C" fn load_file_text(path: *const c_char) -> *mut c_char {
+    let Some(data) = c_path(path).and_then(|path| read(path).ok()) else { return std::ptr::null_mut() };
+    raylib_alloc(&data, true) as *mut c_char
+}
+
+extern "C" fn save_file_text(path: *const c_char, text: *mut c_char) -> bool {
+    let Some(path) = c_path(path) else { return false };
+    if text.is_null() { return false }
+
+    write(path, unsafe { CStr::from_ptr(text) }.to_bytes()).is_ok()
+}
@@ -1,16 +1,21 @@
 //! Window functions (module: `rcore`)
 
-use std::{ffi::{CStr, CString}, mem::ManuallyDrop};
-use crate::{ffi::{self, Image}, prelude::{Vector2, vec2}};
+use std::ffi::{CStr, CString};
+#[cfg(feature = "text")]
+use std::mem::ManuallyDrop;
+use crate::{ffi::{self, Image, Key, TraceLogLevel}, prelude::{Vector2, vec2}, Error};
+#[cfg(feature = "textures")]
+use crate::ffi::PixelFormat;
 
 use bitflags::bitflags;
 
-use super::{other::set_target_fps, Raylib};
+use super::{other::{set_target_fps, set_trace_log_level}, Raylib};
 
 bitflags! {
     /// Window parameters that must be set before window creation (before the call to [`init_window`]).
     ///
     /// For other parameters, see [`WindowFlags`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ConfigFlags: u32 {
         /// Set to support HighDPI
         const HIGHDPI = ffi::ConfigFlags::WindowHighdpi as u32;
@@ -23,6 +28,7 @@ bitflags! {
     /// Window parameters that must be set after window creation (after the call to [`init_window`])
     ///
     /// For other parameters, see [`ConfigFlags`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct WindowFlags: u32 {
         /// Set to try enabling V-Sync on GPU
         const VSYNC_HINT = ffi::ConfigFlags::VsyncHint as u32;
@@ -74,17 +80,277 @@ pub fn init_window(width: i32, height: i32, title: &str, target_fps: i32) -> Ray
     rl
 }
 
+/// Initializes raylib without a window, for rendering offscreen (in tests or on servers).
+///
+/// Everything is drawn to a framebuffer of the given size, which can be read back with
+/// [`Image::load_from_screen`](crate::textures::image::Image::load_from_screen) or [`take_screenshot`](super::other::take_screenshot).
+/// There are no input events, and [`window_should_close`] only returns `true` after [`quit`].
+///
+/// This doesn't need a display or a gpu: the OpenGL context is created with EGL on Mesa's surfaceless platform,
+/// which renders on the cpu with llvmpipe when there is no gpu. EGL and Mesa must be installed
+/// (`libegl1` and `libegl-mesa0` on Debian and Ubuntu), so this is mostly useful on Linux.
+/// Only available with the GLFW platform, and the vendored raylib (not with the `system` feature or `RAYLIB_SYS_DIR`).
+///
+/// Returns [`Error::WindowInitFailed`] if raylib isn't the vendored one,
+/// or if the null platform or its graphics context is unavailable (checked by creating a window before raylib does).
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut init_headless(64, 64).unwrap();
+/// begin_drawing(rl, |rl| {
+///     clear_background(rl, Color::BLUE);
+/// });
+/// let image = Image::load_from_screen(rl).unwrap();
+/// assert_eq!(image.get_color(10, 10), Some(Color::BLUE));
+/// ```
+#[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+pub fn init_headless(width: i32, height: i32) -> Result<Raylib, Error> {
+    WindowBuilder::new(width, height, "raylib").headless(true).build()
+}
+
 pub fn init_window_cstr(width: i32, height: i32, title: &CStr) -> Raylib {
     unsafe { ffi::InitWindow(width, height, title.as_ptr()) }
-    let mut this = Raylib { 
-        automation_event_set: false,
-        automation_event_recording: false,
-        quit_requested: false,
-        default_font: ManuallyDrop::new(None).into(),
-        _private: std::marker::PhantomData
-    };
-    this.load_default_font();
-    this
+    Raylib::initialized()
+}
+
+impl Raylib {
+    /// Creates the handle, once the window was initialized.
+    fn initialized() -> Raylib {
+        #[cfg(feature = "custom_frame_control")]
+        super::other::frame_control::reset();
+
+        #[cfg_attr(not(feature = "text"), allow(unused_mut))]
+        let mut this = Raylib { 
+            automation_event_set: false,
+            automation_event_recording: false,
+            quit_requested: false,
+            #[cfg(feature = "text")]
+            default_font: ManuallyDrop::new(None).into(),
+            _private: std::marker::PhantomData
+        };
+        #[cfg(feature = "text")]
+        this.load_default_font();
+        this
+    }
+}
+
+/// Configures the window and graphics context before initializing raylib.
+///
+/// Parameters are applied in the order raylib needs them:
+/// the trace log level and [`ConfigFlags`] before the window is created, then the monitor, size limits, position, [`WindowFlags`], icon and exit key.
+/// # Example
+/// ```
+/// # use raylib::prelude::*;
+/// let rl = &mut WindowBuilder::new(800, 600, "Builder")
+///     .msaa_4x(true)
+///     .vsync(true)
+///     .resizable(true)
+///     .min_size(400, 300)
+///     .exit_key(None)
+///     .trace_log_level(TraceLogLevel::Warning)
+///     .build()
+///     .unwrap();
+/// while !window_should_close(rl) {
+///     begin_drawing(rl, |rl| {
+///         clear_background(rl, Color::RAYWHITE);
+///     });
+///     # break
+/// }
+/// ```
+pub struct WindowBuilder {
+    width: i32,
+    height: i32,
+    title: String,
+    target_fps: Option<i32>,
+    config_flags: ConfigFlags,
+    window_flags: WindowFlags,
+    monitor: Option<i32>,
+    #[cfg(feature = "textures")]
+    icon: Option<crate::prelude::Image>,
+    min_size: Option<(i32, i32)>,
+    max_size: Option<(i32, i32)>,
+    position: Option<(i32, i32)>,
+    exit_key: Option<Key>,
+    trace_log_level: Option<TraceLogLevel>,
+    #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+    headless: bool
+}
+
+impl WindowBuilder {
+    /// Starts configuring a window of the given size.
+    /// A size of 0 uses the size of the monitor.
+    pub fn new(width: i32, height: i32, title: &str) -> Self {
+        WindowBuilder {
+            width,
+            height,
+            title: title.to_owned(),
+            target_fps: None,
+            config_flags: ConfigFlags::empty(),
+            window_flags: WindowFlags::empty(),
+            monitor: None,
+            #[cfg(feature = "textures")]
+            icon: None,
+            min_size: None,
+            max_size: None,
+            position: None,
+            exit_key: None,
+            trace_log_level: None,
+            #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+            headless: false
+        }
+    }
+
+    /// Sets the maximum FPS (unlimited by default).
+    pub fn target_fps(mut self, fps: i32) -> Self {
+        self.target_fps = Some(fps);
+        self
+    }
+
+    /// Adds the given [`ConfigFlags`].
+    pub fn config_flags(mut self, flags: ConfigFlags) -> Self {
+        self.config_flags |= flags;
+        self
+    }
+    /// Adds the given [`WindowFlags`].
+    pub fn window_flags(mut self, flags: WindowFlags) -> Self {
+        self.window_flags |= flags;
+        self
+    }
+
+    /// Tries enabling 4x multisample anti-aliasing.
+    pub fn msaa_4x(mut self, enabled: bool) -> Self {
+        self.config_flags.set(ConfigFlags::MSAA_4X_HINT, enabled);
+        self
+    }
+    /// Scales the window with the monitor's DPI.
+    pub fn highdpi(mut self, enabled: bool) -> Self {
+        self.config_flags.set(ConfigFlags::HIGHDPI, enabled);
+        self
+    }
+    /// Allows a transparent framebuffer.
+    pub fn transparent(mut self, enabled: bool) -> Self {
+        self.config_flags.set(ConfigFlags::TRANSPARENT, enabled);
+        self
+    }
+    /// Tries enabling V-Sync.
+    pub fn vsync(mut self, enabled: bool) -> Self {
+        self.window_flags.set(WindowFlags::VSYNC_HINT, enabled);
+        self
+    }
+    /// Allows the user to resize the window.
+    pub fn resizable(mut self, enabled: bool) -> Self {
+        self.window_flags.set(WindowFlags::RESIZABLE, enabled);
+        self
+    }
+    /// Starts in fullscreen (on the [target monitor](WindowBuilder::monitor)).
+    pub fn fullscreen(mut self, enabled: bool) -> Self {
+        self.window_flags.set(WindowFlags::FULLSCREEN, enabled);
+        self
+    }
+    /// Removes the window decoration (frame and buttons).
+    pub fn undecorated(mut self, enabled: bool) -> Self {
+        self.window_flags.set(WindowFlags::UNDECORATED, enabled);
+        self
+    }
+
+    /// Opens the window on the monitor at the given index (see [`Monitor::index`]).
+    /// Ignored if there is no such monitor.
+    pub fn monitor(mut self, monitor: i32) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+    /// Sets the window icon (converted to RGBA 32bit if needed).
+    #[cfg(feature = "textures")]
+    pub fn icon(mut self, icon: crate::prelude::Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+    /// Sets the minimum dimensions of a resizable window.
+    pub fn min_size(mut self, width: i32, height: i32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+    /// Sets the maximum dimensions of a resizable window.
+    pub fn max_size(mut self, width: i32, height: i32) -> Self {
+        self.max_size = Some((width, height));
+        self
+    }
+    /// Sets the position of the window on the screen.
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+    /// Sets the key that closes the window ([`Key::Escape`] by default), `None` to disable it.
+    pub fn exit_key(mut self, key: Option<Key>) -> Self {
+        self.exit_key = Some(key.unwrap_or(Key::Null));
+        self
+    }
+    /// Sets the minimum level of the messages raylib logs, including the ones printed while initializing.
+    pub fn trace_log_level(mut self, level: TraceLogLevel) -> Self {
+        self.trace_log_level = Some(level);
+        self
+    }
+    /// Renders offscreen instead of opening a window, see [`init_headless`].
+    #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    /// Creates the window.
+    ///
+    /// Returns [`Error::InvalidData`] if the title contains null characters,
+    /// and [`Error::WindowInitFailed`] if the window or the graphics context could not be created.
+    ///
+    /// NOTE: Raylib doesn't stop when its platform fails to initialize (like when there is no display),
+    /// so the GLFW and SDL platforms are initialized once beforehand to check.
+    /// This isn't possible when linking an installed raylib (the `system` feature or `RAYLIB_SYS_DIR`), which then crashes instead.
+    pub fn build(self) -> Result<Raylib, Error> {
+        let title = CString::new(self.title)?;
+
+        if let Some(level) = self.trace_log_level { set_trace_log_level(level) }
+        set_config_flags(self.config_flags);
+
+        #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+        if self.headless && !super::headless::select_null_platform() {
+            return Err(Error::WindowInitFailed)
+        }
+
+        if !platform_available() {
+            #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+            if self.headless { super::headless::reset_platform() }
+            return Err(Error::WindowInitFailed)
+        }
+
+        unsafe { ffi::InitWindow(self.width, self.height, title.as_ptr()) }
+
+        #[cfg(not(any(feature = "platform-sdl", feature = "platform-rgfw")))]
+        if self.headless { super::headless::reset_platform() }
+
+        if !unsafe { ffi::IsWindowReady() } {
+            return Err(Error::WindowInitFailed)
+        }
+        let mut rl = Raylib::initialized();
+
+        if let Some(monitor) = self.monitor.and_then(|index| get_monitor(&rl, index)) { set_window_monitor(&mut rl, monitor) }
+        if let Some((width, height)) = self.min_size { set_window_min_size(&mut rl, width, height) }
+        if let Some((width, height)) = self.max_size { set_window_max_size(&mut rl, width, height) }
+        if let Some((x, y)) = self.position { set_window_position(&mut rl, x, y) }
+        if !self.window_flags.is_empty() { set_window_state(&mut rl, self.window_flags) }
+
+        #[cfg(feature = "textures")]
+        if let Some(mut icon) = self.icon {
+            if icon.format() != PixelFormat::UncompressedR8G8B8A8 {
+                icon.convert_format(PixelFormat::UncompressedR8G8B8A8);
+            }
+            // raylib copies the pixels
+            set_window_icon(&mut rl, unsafe { icon.get_ffi_image() });
+        }
+        if let Some(key) = self.exit_key { super::input::set_exit_key(&mut rl, key) }
+        if let Some(fps) = self.target_fps { set_target_fps(&rl, fps) }
+
+        Ok(rl)
+    }
 }
 
 /// Check if application should close ([`Key::Escape`][`ffi::Key::Escape`] pressed or windows close icon clicked)
@@ -154,9 +420,19 @@ pub fn set_window_icons(_: &mut Raylib, images: &[Image]) {
 pub fn set_window_title(_: &mut Raylib, title: &CStr) { unsafe { ffi::SetWindowTitle(title.as_ptr()) } }
 /// Set window position on screen (only PLATFORM_DESKTOP)
 pub fn set_window_position(_: &mut Raylib, x: i32, y: i32) { unsafe { ffi::SetWindowPosition(x, y) } }
-/// TODO: Safer handling for monitor indices
 /// Set monitor for the current window
-pub fn set_window_monitor(_: &mut Raylib, monitor: i32) { unsafe { ffi::SetWindowMonitor(monitor) } }
+/// (moves a fullscreen window to the monitor, and centers a windowed one on it)
+pub fn set_window_monitor(_: &mut Raylib, monitor: Monitor) { unsafe { ffi::SetWindowMonitor(monitor.0) } }
+/// Makes the window fullscreen on the given monitor, if it isn't already
+pub fn set_fullscreen_on(rl: &mut Raylib, monitor: Monitor) {
+    set_window_monitor(rl, monitor);
+    if !is_window_fullscreen(rl) { toggle_fullscreen(rl) }
+}
+/// Makes the window borderless and covering the given monitor, if it isn't already
+pub fn set_borderless_windowed_on(rl: &mut Raylib, monitor: Monitor) {
+    set_window_monitor(rl, monitor);
+    if !is_window_state(rl, WindowFlags::BORDERLESS.bits()) { toggle_borderless_windowed(rl) }
+}
 /// Set window minimum dimensions (for FLAG_WINDOW_RESIZABLE)
 pub fn set_window_min_size(_: &mut Raylib, width: i32, height: i32) { unsafe { ffi::SetWindowMinSize(width, height) } }
 /// Set window maximum dimensions (for FLAG_WINDOW_RESIZABLE)
@@ -170,9 +446,13 @@ pub fn set_window_opacity(_: &mut Raylib, opacity: f32) { unsafe { ffi::SetWindo
 /// Set window focused (only PLATFORM_DESKTOP)
 pub fn set_window_focused(_: &Raylib) { unsafe { ffi::SetWindowFocused() } }
 
-// TODO:
-// /// Get native window handle
-// pub fn GetWindowHandle(void) -> void * ;
+/// Get native window handle.
+/// With the GLFW platform, this is a `HWND` on Windows, a `NSWindow` on macOS, and the `GLFWwindow` on Linux.
+/// It is the `SDL_Window` with the `platform-sdl` feature, and the native window with `platform-rgfw`.
+///
+/// To give the window to other crates, enable the `raw-window-handle` feature, which implements
+/// [`HasWindowHandle`](https://docs.rs/raw-window-handle/0.6/raw_window_handle/trait.HasWindowHandle.html) for [`Raylib`].
+pub fn get_window_handle(_: &Raylib) -> *mut std::ffi::c_void { unsafe { ffi::GetWindowHandle() } }
 
 /// Gets the current screen (window) width
 /// If you need to consider HiDPI, use `Raylib::get_render_width`
@@ -190,29 +470,171 @@ pub fn get_render_height(_: &Raylib) -> f32 { unsafe { ffi::GetRenderHeight() as
 /// Gets the current render size as vector (considering HiDPI)
 pub fn get_render_size(rl: &Raylib) -> Vector2 { vec2(get_render_width(rl), get_render_height(rl)) }
 
+/// A connected monitor.
+///
+/// Get one with [`monitors`], [`get_current_monitor`] or [`get_monitor`].
+/// Monitors are identified by their index in the list of connected monitors:
+/// if a monitor is disconnected, the handle may refer to another monitor, or to none.
+/// Functions given a handle to no monitor return default values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Monitor(i32);
+
+impl Monitor {
+    /// Index of the monitor in the list of connected monitors.
+    pub fn index(self) -> i32 {
+        self.0
+    }
+}
+
+/// Information about a monitor, see [`get_monitor_info`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    pub monitor: Monitor,
+    /// Human-readable name of the monitor.
+    pub name: String,
+    /// Position of the monitor on the virtual desktop.
+    pub position: Vector2,
+    /// Width of the current video mode, in screen coordinates.
+    pub width: i32,
+    /// Height of the current video mode, in screen coordinates.
+    pub height: i32,
+    /// Physical width in millimetres.
+    pub physical_width: i32,
+    /// Physical height in millimetres.
+    pub physical_height: i32,
+    /// Refresh rate of the current video mode, in Hz.
+    pub refresh_rate: i32,
+    /// Content scale set by the operating system (1.0 without scaling).
+    pub scale: Vector2
+}
+
 /// Get number of connected monitors
 pub fn get_monitor_count(_: &Raylib) -> i32 { unsafe { ffi::GetMonitorCount() } }
-/// Get current connected monitor
-pub fn get_current_monitor(_: &Raylib) -> i32 { unsafe { ffi::GetCurrentMonitor() } }
+/// Get the connected monitors (the first one is the primary monitor)
+pub fn monitors(rl: &Raylib) -> impl Iterator<Item = Monitor> { (0..get_monitor_count(rl)).map(Monitor) }
+/// Get the monitor at the given index, `None` if there is no such monitor
+pub fn get_monitor(rl: &Raylib, index: i32) -> Option<Monitor> { (0..get_monitor_count(rl)).contains(&index).then_some(Monitor(index)) }
+/// Get the monitor the window is on
+pub fn get_current_monitor(_: &Raylib) -> Monitor { Monitor(unsafe { ffi::GetCurrentMonitor() }) }
 /// Get specified monitor position
-pub fn get_monitor_position(_: &Raylib, monitor: i32) -> Vector2 { unsafe { ffi::GetMonitorPosition(monitor) } }
+pub fn get_monitor_position(_: &Raylib, monitor: Monitor) -> Vector2 { unsafe { ffi::GetMonitorPosition(monitor.0) } }
 /// Get specified monitor width (current video mode used by monitor)
-pub fn get_monitor_width(_: &Raylib, monitor: i32) -> i32 { unsafe { ffi::GetMonitorWidth(monitor) } }
+pub fn get_monitor_width(_: &Raylib, monitor: Monitor) -> i32 { unsafe { ffi::GetMonitorWidth(monitor.0) } }
 /// Get specified monitor height (current video mode used by monitor)
-pub fn get_monitor_height(_: &Raylib, monitor: i32) -> i32 { unsafe { ffi::GetMonitorHeight(monitor) } }
+pub fn get_monitor_height(_: &Raylib, monitor: Monitor) -> i32 { unsafe { ffi::GetMonitorHeight(monitor.0) } }
 /// Get specified monitor physical width in millimetres
-pub fn get_monitor_physical_width(_: &Raylib, monitor: i32) -> i32 { unsafe { ffi::GetMonitorPhysicalWidth(monitor) } }
+pub fn get_monitor_physical_width(_: &Raylib, monitor: Monitor) -> i32 { unsafe { ffi::GetMonitorPhysicalWidth(monitor.0) } }
 /// Get specified monitor physical height in millimetres
-pub fn get_monitor_physical_height(_: &Raylib, monitor: i32) -> i32 { unsafe { ffi::GetMonitorPhysicalHeight(monitor) } }
+pub fn get_monitor_physical_height(_: &Raylib, monitor: Monitor) -> i32 { unsafe { ffi::GetMonitorPhysicalHeight(monitor.0) } }
 /// Get specified monitor refresh rate
-pub fn get_monitor_refresh_rate(_: &Raylib, monitor: i32) -> i32 { unsafe { ffi::GetMonitorRefreshRate(monitor) } }
+pub fn get_monitor_refresh_rate(_: &Raylib, monitor: Monitor) -> i32 { unsafe { ffi::GetMonitorRefreshRate(monitor.0) } }
+/// Initializes and terminates glfw with the same hints as raylib, to check that `InitWindow` will succeed.
+#[cfg(all(raylib_vendored, not(any(feature = "platform-sdl", feature = "platform-rgfw"))))]
+fn platform_available() -> bool {
+    use std::ffi::c_int;
+
+    extern "C" {
+        #[cfg(target_os = "macos")]
+        fn glfwInitHint(hint: c_int, value: c_int);
+        fn glfwInit() -> c_int;
+        fn glfwTerminate();
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        const GLFW_COCOA_CHDIR_RESOURCES: c_int = 0x00051001;
+        unsafe { glfwInitHint(GLFW_COCOA_CHDIR_RESOURCES, 0) }
+    }
+
+    let available = unsafe { glfwInit() } != 0;
+    if available { unsafe { glfwTerminate() } }
+    available
+}
+/// Initializes and quits SDL's video subsystem, to check that `InitWindow` will succeed.
+#[cfg(all(raylib_vendored, feature = "platform-sdl"))]
+fn platform_available() -> bool {
+    const SDL_INIT_VIDEO: u32 = 0x00000020;
+
+    extern "C" {
+        fn SDL_Init(flags: u32) -> std::ffi::c_int;
+        fn SDL_Quit();
+    }
+
+    let available = unsafe { SDL_Init(SDL_INIT_VIDEO) } >= 0;
+    unsafe { SDL_Quit() }
+    available
+}
+/// The platform can't be checked with RGFW, or with an installed raylib.
+#[cfg(not(all(raylib_vendored, not(feature = "platform-rgfw"))))]
+fn platform_available() -> bool {
+    true
+}
+
+/// Get specified monitor content scale (1.0 without scaling)
+///
+/// Only available with the GLFW platform, this is always 1.0 with the SDL and RGFW platforms.
+/// When linking an installed raylib (the `system` feature or `RAYLIB_SYS_DIR`), glfw's functions aren't exported,
+/// and this is the scale of the window instead (see [`get_window_scale_dpi`]).
+#[cfg(all(raylib_vendored, not(any(feature = "platform-sdl", feature = "platform-rgfw"))))]
+pub fn get_monitor_scale(_: &Raylib, monitor: Monitor) -> Vector2 {
+    extern "C" {
+        fn glfwGetMonitors(count: *mut std::ffi::c_int) -> *mut *mut std::ffi::c_void;
+        fn glfwGetMonitorContentScale(monitor: *mut std::ffi::c_void, x: *mut f32, y: *mut f32);
+    }
+
+    // raylib uses the same indices as glfw
+    let mut count = 0;
+    let monitors = unsafe { glfwGetMonitors(&mut count) };
+    if monitors.is_null() || !(0..count).contains(&monitor.0) { return Vector2::ZERO }
+
+    let mut scale = Vector2::ZERO;
+    unsafe { glfwGetMonitorContentScale(*monitors.add(monitor.0 as usize), &mut scale.x, &mut scale.y) }
+    scale
+}
+/// Get specified monitor content scale (1.0 without scaling)
+///
+/// Only available with the GLFW platform, this is always 1.0 with the SDL and RGFW platforms.
+/// When linking an installed raylib (the `system` feature or `RAYLIB_SYS_DIR`), glfw's functions aren't exported,
+/// and this is the scale of the window instead (see [`get_window_scale_dpi`]).
+#[cfg(all(not(raylib_vendored), not(any(feature = "platform-sdl", feature = "platform-rgfw"))))]
+pub fn get_monitor_scale(_: &Raylib, _: Monitor) -> Vector2 { unsafe { ffi::GetWindowScaleDPI() } }
+/// Get specified monitor content scale (1.0 without scaling)
+///
+/// Only available with the GLFW platform, this is always 1.0 with the SDL and RGFW platforms.
+#[cfg(any(feature = "platform-sdl", feature = "platform-rgfw"))]
+pub fn get_monitor_scale(_: &Raylib, _: Monitor) -> Vector2 { Vector2::ONE }
 /// Get window position XY on monitor
 pub fn get_window_position(_: &Raylib) -> Vector2 { unsafe { ffi::GetWindowPosition() } }
 /// Get window scale DPI factor
 pub fn get_window_scale_dpi(_: &Raylib) -> Vector2 { unsafe { ffi::GetWindowScaleDPI() } }
 /// Get the human-readable, UTF-8 encoded name of the specified monitor
-pub fn get_monitor_name(_: &Raylib, monitor: i32) -> &CStr {
-    unsafe { CStr::from_ptr(ffi::GetMonitorName(monitor)) }
+pub fn get_monitor_name(_: &Raylib, monitor: Monitor) -> &CStr {
+    unsafe { CStr::from_ptr(ffi::GetMonitorName(monitor.0)) }
+}
+/// Get all the information about a monitor at once, `None` if it is not connected anymore
+/// # Example
+/// List the connected monitors:
+/// ```
+/// # use raylib::prelude::*;
+/// # let rl = &mut init_window(100, 100, "monitors", 60);
+/// for info in monitors(rl).filter_map(|monitor| get_monitor_info(rl, monitor)) {
+///     println!("{}: {}x{} at {}Hz", info.name, info.width, info.height, info.refresh_rate);
+/// }
+/// ```
+pub fn get_monitor_info(rl: &Raylib, monitor: Monitor) -> Option<MonitorInfo> {
+    if !(0..get_monitor_count(rl)).contains(&monitor.0) { return None }
+
+    Some(MonitorInfo {
+        monitor,
+        name: get_monitor_name(rl, monitor).to_string_lossy().into_owned(),
+        position: get_monitor_position(rl, monitor),
+        width: get_monitor_width(rl, monitor),
+        height: get_monitor_height(rl, monitor),
+        physical_width: get_monitor_physical_width(rl, monitor),
+        physical_height: get_monitor_physical_height(rl, monitor),
+        refresh_rate: get_monitor_refresh_rate(rl, monitor),
+        scale: get_monitor_scale(rl, monitor)
+    })
 }
 /// Set clipboard text content   
 pub fn set_clipboard_text(_: &Raylib, text: &CStr) { unsafe { ffi::SetClipboardText(text.as_ptr()) } }
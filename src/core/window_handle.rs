@@ -0,0 +1,143 @@
+//! [`raw_window_handle`] implementations for [`Raylib`], with the `raw-window-handle` feature.
+//!
+//! Supports Windows, macOS, and X11 or Wayland on Linux and the BSDs, with the GLFW platform.
+//! Handles are not available with the SDL and RGFW platforms.
+//!
+//! Except on Windows, the handles come from glfw's functions, which an installed raylib doesn't export:
+//! they are not available either when linking it (with the `system` feature or `RAYLIB_SYS_DIR`).
+
+use raw_window_handle::{DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, WindowHandle};
+
+use super::Raylib;
+
+impl HasWindowHandle for Raylib {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        let raw = platform::window_handle()?;
+        // the window lives as long as the `Raylib` handle
+        Ok(unsafe { WindowHandle::borrow_raw(raw) })
+    }
+}
+
+impl HasDisplayHandle for Raylib {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        let raw = platform::display_handle()?;
+        Ok(unsafe { DisplayHandle::borrow_raw(raw) })
+    }
+}
+
+#[cfg(all(target_os = "windows", not(any(feature = "platform-sdl", feature = "platform-rgfw"))))]
+mod platform {
+    use std::num::NonZeroIsize;
+
+    use raw_window_handle::{HandleError, RawDisplayHandle, RawWindowHandle, Win32WindowHandle, WindowsDisplayHandle};
+
+    use crate::ffi;
+
+    pub(super) fn window_handle() -> Result<RawWindowHandle, HandleError> {
+        // raylib returns the `HWND`
+        let hwnd = NonZeroIsize::new(unsafe { ffi::GetWindowHandle() } as isize).ok_or(HandleError::Unavailable)?;
+        Ok(Win32WindowHandle::new(hwnd).into())
+    }
+
+    pub(super) fn display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Ok(WindowsDisplayHandle::new().into())
+    }
+}
+
+#[cfg(all(target_os = "macos", raylib_vendored, not(any(feature = "platform-sdl", feature = "platform-rgfw"))))]
+mod platform {
+    use std::{ffi::c_void, ptr::NonNull};
+
+    use raw_window_handle::{AppKitDisplayHandle, AppKitWindowHandle, HandleError, RawDisplayHandle, RawWindowHandle};
+
+    extern "C" {
+        fn glfwGetCurrentContext() -> *mut c_void;
+        fn glfwGetCocoaView(window: *mut c_void) -> *mut c_void;
+    }
+
+    pub(super) fn window_handle() -> Result<RawWindowHandle, HandleError> {
+        // raylib returns the `NSWindow`, but the handle needs its content view
+        let view = NonNull::new(unsafe { glfwGetCocoaView(glfwGetCurrentContext()) }).ok_or(HandleError::Unavailable)?;
+        Ok(AppKitWindowHandle::new(view).into())
+    }
+
+    pub(super) fn display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Ok(AppKitDisplayHandle::new().into())
+    }
+}
+
+#[cfg(all(
+    unix, not(any(target_os = "macos", target_os = "ios", target_os = "android", target_os = "emscripten")),
+    raylib_vendored, not(any(feature = "platform-sdl", feature = "platform-rgfw"))
+))]
+mod platform {
+    use std::{ffi::{c_int, c_ulong, c_void}, ptr::NonNull};
+
+    use raw_window_handle::{HandleError, RawDisplayHandle, RawWindowHandle, WaylandDisplayHandle, WaylandWindowHandle, XlibDisplayHandle, XlibWindowHandle};
+
+    use crate::ffi;
+
+    const GLFW_PLATFORM_WAYLAND: c_int = 0x00060003;
+    const GLFW_PLATFORM_X11: c_int = 0x00060004;
+
+    extern "C" {
+        fn glfwGetPlatform() -> c_int;
+        fn glfwGetX11Display() -> *mut c_void;
+        fn glfwGetX11Window(window: *mut c_void) -> c_ulong;
+        fn glfwGetWaylandDisplay() -> *mut c_void;
+        fn glfwGetWaylandWindow(window: *mut c_void) -> *mut c_void;
+    }
+
+    pub(super) fn window_handle() -> Result<RawWindowHandle, HandleError> {
+        // raylib returns the `GLFWwindow`, glfw chooses between X11 and Wayland at runtime
+        let window = unsafe { ffi::GetWindowHandle() };
+        if window.is_null() { return Err(HandleError::Unavailable) }
+
+        match unsafe { glfwGetPlatform() } {
+            GLFW_PLATFORM_X11 => match unsafe { glfwGetX11Window(window) } {
+                0 => Err(HandleError::Unavailable),
+                id => Ok(XlibWindowHandle::new(id).into())
+            },
+            GLFW_PLATFORM_WAYLAND => {
+                let surface = NonNull::new(unsafe { glfwGetWaylandWindow(window) }).ok_or(HandleError::Unavailable)?;
+                Ok(WaylandWindowHandle::new(surface).into())
+            }
+            _ => Err(HandleError::NotSupported)
+        }
+    }
+
+    pub(super) fn display_handle() -> Result<RawDisplayHandle, HandleError> {
+        match unsafe { glfwGetPlatform() } {
+            GLFW_PLATFORM_X11 => {
+                let display = NonNull::new(unsafe { glfwGetX11Display() }).ok_or(HandleError::Unavailable)?;
+                // glfw loads Xlib at runtime, so `DefaultScreen` can't be called here: assume the default screen is the first
+                Ok(XlibDisplayHandle::new(Some(display), 0).into())
+            }
+            GLFW_PLATFORM_WAYLAND => {
+                let display = NonNull::new(unsafe { glfwGetWaylandDisplay() }).ok_or(HandleError::Unavailable)?;
+                Ok(WaylandDisplayHandle::new(display).into())
+            }
+            _ => Err(HandleError::NotSupported)
+        }
+    }
+}
+
+#[cfg(not(all(
+    any(
+        target_os = "windows",
+        all(target_os = "macos", raylib_vendored),
+        all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "android", target_os = "emscripten")), raylib_vendored)
+    ),
+    not(any(feature = "platform-sdl", feature = "platform-rgfw"))
+)))]
+mod platform {
+    use raw_window_handle::{HandleError, RawDisplayHandle, RawWindowHandle};
+
+    pub(super) fn window_handle() -> Result<RawWindowHandle, HandleError> {
+        Err(HandleError::NotSupported)
+    }
+
+    pub(super) fn display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Err(HandleError::NotSupported)
+    }
+}
@@ -0,0 +1,92 @@
+/// An error that can happen when loading or creating raylib objects.
+#[derive(Debug)]
+pub enum Error {
+    /// There was an error when reading or writing a file.
+    Io(std::io::Error),
+    /// The file extension is not a known format for this kind of object.
+    UnknownFormat,
+    /// Support for this format (or api) was not compiled into raylib.
+    /// Enable the given cargo feature to use it.
+    UnsupportedFormat { feature: &'static str },
+    /// The data could not be decoded, or is not valid for this kind of object.
+    InvalidData,
+    /// The object could not be created on the GPU.
+    GpuUploadFailed,
+    /// A shader failed to compile or link.
+    ShaderCompile(crate::core::shader::ShaderError),
+    /// The size (or format) of the given data does not correspond to its destination.
+    SizeMismatch,
+    /// The window or its graphics context could not be created.
+    WindowInitFailed,
+    /// The audio device could not be initialized.
+    DeviceUnavailable,
+    /// There are already too many audio callbacks and processors registered.
+    TooManyCallbacks
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {e}"),
+            Error::UnknownFormat => write!(f, "unknown file format"),
+            Error::UnsupportedFormat { feature } => write!(f, "support for this format was not compiled in (enable the `{feature}` feature)"),
+            Error::InvalidData => write!(f, "invalid data"),
+            Error::GpuUploadFailed => write!(f, "failed to upload data to the gpu"),
+            Error::ShaderCompile(e) => write!(f, "{e}"),
+            Error::SizeMismatch => write!(f, "data size does not match"),
+            Error::WindowInitFailed => write!(f, "failed to initialize the window"),
+            Error::DeviceUnavailable => write!(f, "the audio device could not be initialized"),
+            Error::TooManyCallbacks => write!(f, "too many audio callbacks and processors are registered")
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::ShaderCompile(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<crate::core::shader::ShaderError> for Error {
+    fn from(value: crate::core::shader::ShaderError) -> Self {
+        Error::ShaderCompile(value)
+    }
+}
+
+#[cfg(feature = "audio")]
+impl From<crate::audio::AudioError> for Error {
+    fn from(value: crate::audio::AudioError) -> Self {
+        use crate::audio::AudioError;
+        match value {
+            AudioError::Io(e) => Error::Io(e),
+            AudioError::UnknownFormat => Error::UnknownFormat,
+            AudioError::UnsupportedFormat { feature } => Error::UnsupportedFormat { feature },
+            AudioError::InvalidData => Error::InvalidData,
+            AudioError::DeviceUnavailable => Error::DeviceUnavailable,
+            AudioError::TooManyCallbacks => Error::TooManyCallbacks
+        }
+    }
+}
+
+impl From<std::ffi::NulError> for Error {
+    fn from(_: std::ffi::NulError) -> Self {
+        Error::InvalidData
+    }
+}
+
+/// Converts a path to a C string, for raylib functions that take file names.
+#[cfg(feature = "models")]
+pub(crate) fn path_to_cstring(path: &std::path::Path) -> Result<std::ffi::CString, Error> {
+    std::ffi::CString::new(path.as_os_str().as_encoded_bytes())
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput).into())
+}
@@ -28,12 +28,21 @@ pub mod rlgl;
 
 pub mod core;
 pub mod math;
+#[cfg(feature = "shapes")]
 pub mod shapes;
 pub mod collisions;
+#[cfg(feature = "textures")]
 pub mod textures;
+#[cfg(feature = "text")]
 pub mod text;
+#[cfg(feature = "models")]
 pub mod model;
+#[cfg(feature = "audio")]
+pub mod audio;
 
 mod cstr_macro;
+mod error;
+
+pub use error::Error;
 
 pub mod prelude;
@@ -1,6 +1,9 @@
-use std::ffi::c_void;
 pub use crate::ffi::Color;
-use crate::{ffi::{self, PixelFormat}, prelude::{Vector3, Vector4}};
+use crate::ffi::PixelFormat;
+#[cfg(feature = "textures")]
+use std::ffi::c_void;
+#[cfg(feature = "textures")]
+use crate::{ffi, prelude::{Vector3, Vector4}};
 
 impl Color {
     pub const LIGHTGRAY: Color = Color::rgb(200, 200, 200);
@@ -65,7 +68,11 @@ impl Color {
     pub const fn hex(hex: u32) -> Self {
         Color { r: (hex & 0xff) as u8, g: ((hex >> 8) & 0xff) as u8, b: ((hex >> 16) & 0xff) as u8, a: (hex >> 24) as u8 }
     }
+}
 
+/// Color functions, part of raylib's `rtextures` module.
+#[cfg(feature = "textures")]
+impl Color {
     /// Get color with alpha applied, alpha goes from 0.0f to 1.0f
     pub fn fade(self, alpha: f32) -> Self { unsafe { ffi::Fade(self, alpha) } }
     /// Get hexadecimal value for a Color
@@ -150,6 +157,7 @@ impl PixelFormat {
 }
 
 /// Get the size of an image in bytes given a certain pixel format
+#[cfg(feature = "textures")]
 pub fn get_pixel_data_size(width: i32, height: i32, format: PixelFormat) -> i32 {
     unsafe { ffi::GetPixelDataSize(width, height, format as i32) }
 }
@@ -1,4 +1,4 @@
-use crate::{ffi, prelude::{Color, MaterialMapIndex, Raylib, Shader, WeakTexture}};
+use crate::{ffi, prelude::{Color, MaterialMapIndex, Raylib, Shader, WeakTexture}, Error};
 
 use std::ffi::CStr;
 
@@ -14,31 +14,36 @@ impl ffi::Material {
 
 impl<'a> Material<'a> {
     /// Loads all materials from a model (`.mtl`) file.
-    /// Returns `None` if raylib was not compiled with `mtl` support, if the given file is not an `mtl` file,
-    /// or if the given file is invalid.
-    pub fn load_multiple(path: impl AsRef<std::path::Path>) -> Option<Vec<Self>> {
-        let cstr = std::ffi::CString::new(path.as_ref().as_os_str().as_encoded_bytes()).unwrap();
-        Material::load_multiple_cstr(&cstr)
+    /// Returns [`Error::UnsupportedFormat`] if raylib was not compiled with `mtl` support,
+    /// [`Error::UnknownFormat`] if the given file is not an `mtl` file,
+    /// and [`Error::InvalidData`] if the given file is invalid.
+    pub fn load_multiple(path: impl AsRef<std::path::Path>) -> Result<Vec<Self>, Error> {
+        let path = path.as_ref();
+        if !path.extension().is_some_and(|e| e.eq_ignore_ascii_case("mtl")) { return Err(Error::UnknownFormat) }
+        Material::load_multiple_cstr(&crate::error::path_to_cstring(path)?)
     }
 
     /// Loads all materials from a model (`.mtl`) file.
-    /// Returns `None` if raylib was not compiled with `mtl` support, if the given file is not an `mtl` file,
-    /// or if the given file is invalid.
-    pub fn load_multiple_cstr(filename: &CStr) -> Option<Vec<Self>> {
+    /// Returns [`Error::UnsupportedFormat`] if raylib was not compiled with `mtl` support,
+    /// and [`Error::InvalidData`] if the given file is not an `mtl` file or is invalid.
+    pub fn load_multiple_cstr(filename: &CStr) -> Result<Vec<Self>, Error> {
+        if !cfg!(feature = "mtl") { return Err(Error::UnsupportedFormat { feature: "mtl" }) }
+
         // load ffi materials
         let mut count = 0;
-        let ptr = unsafe { ffi::LoadMaterials(filename.as_ptr(), &mut count as *mut _) };
+        let base = std::path::Path::new(filename.to_str().unwrap_or_default());
+        let ptr = crate::core::vfs::with_base_directory(base, || unsafe { ffi::LoadMaterials(filename.as_ptr(), &mut count as *mut _) });
 
-        if ptr.is_null() || count == 0 { return None }
+        if ptr.is_null() || count == 0 { return Err(Error::InvalidData) }
 
         // copy slice contents to rust vector
         let slice = unsafe { std::slice::from_raw_parts(ptr, count as usize) };
-        let materials = slice.iter().map(|m| Material::from_ffi(*m)).collect();
+        let materials: Option<Vec<_>> = slice.iter().map(|m| Material::from_ffi(*m)).collect();
 
         // free raylib memory
         unsafe { ffi::MemFree(ptr as *mut _) }
 
-        materials
+        materials.ok_or(Error::InvalidData)
     }
 
     /// Load default material (supports: `MaterialMapIndex::{Albedo, Metalness, Normal}`).
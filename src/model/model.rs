@@ -1,6 +1,6 @@
 use std::ffi::CStr;
 
-use crate::{ffi, prelude::{BoundingBox, Camera, Color, Matrix, Mesh, Rectangle, Transform, Vector2, Vector3, WeakTexture}};
+use crate::{ffi, prelude::{BoundingBox, Camera, Color, Matrix, Mesh, Rectangle, Transform, Vector2, Vector3, WeakTexture}, Error};
 use super::{DrawHandle3D, material::Material};
 
 /// A raylib model.
@@ -19,27 +19,43 @@ pub struct Model(ffi::Model);
 impl Model {
     /// Load a model from a file (meshes and materials).
     ///
-    /// Returns `None` if the given file does not exist,
-    /// if its not in a valid format, if it is empty, or if the given path is not a valid C string.
-    ///
     /// Supports the following formats (if they were compiled into raylib): `obj`, `iqm`, `gltf`, `vox` and `m3d`.
-    pub fn load(path: impl AsRef<std::path::Path>) -> Option<Model> {
-        let cstr = std::ffi::CString::new(path.as_ref().as_os_str().as_encoded_bytes()).unwrap();
-        Model::load_cstr(&cstr)
+    ///
+    /// Returns [`Error::UnknownFormat`] if the file extension isn't one of those,
+    /// [`Error::UnsupportedFormat`] if support for it was not compiled into raylib,
+    /// [`Error::Io`] if the file does not exist or the path is not a valid C string,
+    /// and [`Error::InvalidData`] if the file is invalid or empty.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Model, Error> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_ascii_lowercase();
+        let (feature, supported) = match extension.as_str() {
+            "obj" => ("obj", cfg!(feature = "obj")),
+            "iqm" => ("iqm", cfg!(feature = "iqm")),
+            "gltf" | "glb" => ("gltf", cfg!(feature = "gltf")),
+            "vox" => ("vox", cfg!(feature = "vox")),
+            "m3d" => ("m3d", cfg!(feature = "m3d")),
+            _ => return Err(Error::UnknownFormat)
+        };
+        if !supported { return Err(Error::UnsupportedFormat { feature }) }
+        if !crate::core::vfs::exists(path) { return Err(std::io::Error::from(std::io::ErrorKind::NotFound).into()) }
+
+        Model::load_cstr(&crate::error::path_to_cstring(path)?)
     }
 
     /// Load a model from a file (meshes and materials).
     ///
-    /// Returns `None` if the given file does not exist, if its not in a valid format or if it is empty.
+    /// Returns [`Error::InvalidData`] if the given file does not exist, if its not in a valid format or if it is empty.
     ///
     /// Supports the following formats (if they were compiled into raylib): `obj`, `iqm`, `gltf`, `vox` and `m3d`.
-    pub fn load_cstr(path: &CStr) -> Option<Model> {
-        let model = unsafe { ffi::LoadModel(path.as_ptr()) };
+    pub fn load_cstr(path: &CStr) -> Result<Model, Error> {
+        // materials and textures are referenced relative to the model
+        let base = std::path::Path::new(path.to_str().unwrap_or_default());
+        let model = crate::core::vfs::with_base_directory(base, || unsafe { ffi::LoadModel(path.as_ptr()) });
         if model.meshCount == 0 || model.meshes.is_null() {
-            return None
+            return Err(Error::InvalidData)
         }
 
-        Some(Model(model))
+        Ok(Model(model))
     }
 }
 
@@ -1,3 +1,5 @@
+pub use crate::Error;
+
 pub use crate::ffi::{Ray, RayCollision, Transform};
 pub use crate::ffi::{AutomationEvent, BoneInfo, BoundingBox, GlyphInfo, MaterialMap, NPatchInfo};
 pub use crate::ffi::{
@@ -15,16 +17,24 @@ pub use crate::core::{
     vr::*,
     automation::*,
     input::*,
-    other::*
+    other::*,
+    logging::*,
+    vfs::{Vfs, MemoryVfs, DirectoryVfs, Overlay, TarVfs, normalize_path, set_vfs, clear_vfs},
+    pack::{AssetPack, AssetPackBuilder},
+    assets::{AssetManager, Handle, ManagedAsset, Reload},
+    app::*
 };
 
+#[cfg(feature = "shapes")]
 pub use crate::shapes::*;
 
+#[cfg(feature = "textures")]
 pub use crate::textures::{
     image::*,
     texture::*,
 };
 
+#[cfg(feature = "text")]
 pub use crate::text::{
     font::*,
     cache::*
@@ -42,6 +52,7 @@ pub use crate::math::{
 };
 
 pub use crate::collisions::*;
+#[cfg(feature = "models")]
 pub use crate::model::{
     begin_mode3d,
     shapes::*,
@@ -49,3 +60,20 @@ pub use crate::model::{
     mesh::*,
     material::*
 };
+#[cfg(feature = "audio")]
+pub use crate::audio::{
+    init_audio_device,
+    AudioDevice, AudioError, AudioFiletype,
+    wave::*,
+    sound::*,
+    music::*,
+    stream::*,
+    processor::*,
+    effects::*,
+    synth::*,
+    pool::*,
+    player::*,
+    spatial::*,
+    capture::*,
+    spectrum::*
+};
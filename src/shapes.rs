@@ -4,9 +4,12 @@ use std::f32::consts::PI;
 
 use ffi::Rectangle;
 
-use crate::{core::draw::DrawHandle, ffi, prelude::{vec2, Color, Vector2, WeakTexture}};
+use crate::{core::draw::DrawHandle, ffi, prelude::{vec2, Color, Vector2}};
+#[cfg(feature = "textures")]
+use crate::prelude::WeakTexture;
 
 /// Set texture and rectangle to be used on shapes drawing.
+#[cfg(feature = "textures")]
 #[inline]
 pub fn set_shapes_texture(_rl: &DrawHandle, texture: impl Into<WeakTexture>, source: Rectangle) {
     unsafe { ffi::SetShapesTexture(*texture.into().get_ffi(), source) }
@@ -145,5 +145,15 @@ pub fn draw_glyph<F: FontCache>(rl: &DrawHandle, atlas: &F, glyph_index: u16, po
 }
 
 pub fn draw_fps(_rl: &DrawHandle, pos: Vector2) {
+    #[cfg(not(feature = "custom_frame_control"))]
     unsafe { ffi::DrawFPS(pos.x as i32, pos.y as i32) }
+
+    // raylib's `GetFPS` is compiled out with custom frame control, same as `DrawFPS` with the fps tracked in Rust
+    #[cfg(feature = "custom_frame_control")]
+    {
+        let fps = crate::core::other::get_fps(_rl);
+        let color = if fps < 15 { Color::RED } else if fps < 30 { Color::ORANGE } else { Color::LIME };
+        let text = std::ffi::CString::new(format!("{fps:2} FPS")).unwrap();
+        unsafe { ffi::DrawText(text.as_ptr(), pos.x as i32, pos.y as i32, 20, color) }
+    }
 }
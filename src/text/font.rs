@@ -1,7 +1,7 @@
 use std::{cell::RefCell, hash::Hash, num::NonZeroU16, path::Path};
 use hashbrown::HashMap;
 
-use crate::prelude::{draw_texture_pro, Color, DrawHandle, PixelFormat, Raylib, Rectangle, Texture, TextureFilter, Vector2};
+use crate::{prelude::{draw_texture_pro, Color, DrawHandle, PixelFormat, Raylib, Rectangle, Texture, TextureFilter, Vector2}, Error};
 
 use super::cache::{FontCache, LineMetrics, Metrics};
 
@@ -10,6 +10,7 @@ pub struct TrueTypeFont(fontdue::Font);
 
 impl TrueTypeFont {
     /// Create a font from binary `ttf` or `otf` data.
+    /// Returns [`Error::InvalidData`] if the data is not a valid font.
     /// 
     /// # Examples
     /// Load a font present in the project directory and draw text with it:
@@ -17,7 +18,7 @@ impl TrueTypeFont {
     /// # use raylib::prelude::*;
     /// # let rl = &mut init_window(800, 800, "Font rendering", 60);
     /// let font = TrueTypeFont::from_bytes(include_bytes!("../../assets/TerminusTTF.ttf").as_slice()).unwrap();
-    /// let font = load_font_ex(rl, font);
+    /// let font = load_font_ex(rl, font, FontParams::default());
     /// while !window_should_close(rl) {
     ///     begin_drawing(rl, |rl| {
     ///         clear_background(rl, Color::RAYWHITE);
@@ -26,8 +27,9 @@ impl TrueTypeFont {
     ///     # break
     /// }
     /// ```
-    pub fn from_bytes(bytes: impl std::ops::Deref<Target = [u8]>) -> Result<Self, &'static str> {
-        Ok(TrueTypeFont(fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default())?))
+    pub fn from_bytes(bytes: impl std::ops::Deref<Target = [u8]>) -> Result<Self, Error> {
+        let font = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default()).map_err(|_| Error::InvalidData)?;
+        Ok(TrueTypeFont(font))
     }
 
     /// Create a font from an already created `fontdue` font.
@@ -72,36 +74,31 @@ impl Hash for FontSizeKey {
 /// # let mut rl = &mut init_window(100, 100, "load_font", 60);
 /// let font = load_font(rl, "TerminusTTF.ttf").unwrap();
 /// begin_drawing(rl, |rl| {
-///     draw_text(rl, &font, "Hello, world!", vec2(20.0, 60.0), Color::BLACK);
+///     draw_text(rl, &font, "Hello, world!", vec2(20.0, 60.0), 20.0, Color::BLACK);
 /// });
 /// ```
-pub fn load_font(rl: &Raylib, path: impl AsRef<Path>) -> std::io::Result<TrueTypeFontCache> {
-    use std::io::{Error, ErrorKind};
-
-    let bytes = std::fs::read(path)?;
-    let font = TrueTypeFont::from_bytes(bytes.as_slice()).map_err(|s| Error::new(ErrorKind::InvalidData, s))?;
+pub fn load_font(rl: &Raylib, path: impl AsRef<Path>) -> Result<TrueTypeFontCache, Error> {
+    let bytes = crate::core::vfs::read(path)?;
+    let font = TrueTypeFont::from_bytes(bytes.as_slice())?;
     Ok(load_font_ex(rl, font, FontParams::default()))
 }
 
-/// Loads a font into raylib from bytes.
-/// 
-/// # Panics
-/// Panics if the given bytes are not a valid font. 
-/// Use [`TrueTypeFont::from_bytes`] and [`load_font_ex`] if you need to handle a potential error. 
+/// Loads a font into raylib from bytes, with default [`FontParams`].
+/// Returns [`Error::InvalidData`] if the given bytes are not a valid font.
 /// 
 /// # Examples
 /// Include a font in the project directory in the executable:
 /// ```
 /// # use raylib::prelude::*;
-/// # let mut rl = &mut init_window(100, 100, "font from bytes");
-/// let font = load_font_bytes(rl, include_bytes!("assets/TerminusTTF.ttf").as_slice());
+/// # let mut rl = &mut init_window(100, 100, "font from bytes", 60);
+/// let font = load_font_bytes(rl, include_bytes!("../../assets/TerminusTTF.ttf").as_slice()).unwrap();
 /// begin_drawing(rl, |rl| {
 ///     draw_text(rl, &font, "Hello, Terminus!", vec2(20.0, 20.0), 20.0, Color::BLACK);
 /// });
 /// ```
-pub fn load_font_bytes(rl: &Raylib, bytes: &[u8]) -> TrueTypeFontCache {
-    let font = TrueTypeFont::from_bytes(bytes).expect("a valid ttf or otf font in bytes");
-    load_font_ex(rl, font, FontParams::default())
+pub fn load_font_bytes(rl: &Raylib, bytes: &[u8]) -> Result<TrueTypeFontCache, Error> {
+    let font = TrueTypeFont::from_bytes(bytes)?;
+    Ok(load_font_ex(rl, font, FontParams::default()))
 }
 
 /// Loads a [`TrueTypeFont`] into raylib with the given [`FontParams`].
@@ -282,7 +279,7 @@ impl AtlasRectangle {
 
             // resize texture
             size *= 2;
-            texture.resize_canvas(texture.width()*2, texture.height()*2, 0, 0, Color::BLANK);
+            texture.resize_canvas(texture.width()*2, texture.height()*2, 0, 0, Color::BLANK).expect("the glyph atlas to grow");
         }
 
         // finished using the new right part, use lower half of resized
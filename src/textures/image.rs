@@ -1,7 +1,7 @@
 use std::{ffi::CStr, collections::HashSet};
 use half::f16;
 
-use crate::{ffi, prelude::{PixelFormat, Rectangle, Raylib, Vector2, Color, get_pixel_data_size, vec2}, cstr};
+use crate::{ffi, prelude::{PixelFormat, Rectangle, Raylib, Vector2, Color, get_pixel_data_size, vec2}, cstr, Error};
 
 
 use super::texture::Texture;
@@ -147,6 +147,7 @@ impl PixelFormat {
 }
 
 /// Defines image filetypes supported by raylib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImageFiletype {
     Png,
     Bmp,
@@ -191,52 +192,106 @@ impl ImageFiletype {
             ImageFiletype::Astc => cstr!(".astc"),
         }
     }
+
+    /// The cargo feature that compiles support for this filetype into raylib.
+    pub fn feature(&self) -> &'static str {
+        match self {
+            ImageFiletype::Png => "png",
+            ImageFiletype::Bmp => "bmp",
+            ImageFiletype::Tga => "tga",
+            ImageFiletype::Jpg => "jpg",
+            ImageFiletype::Gif => "gif",
+            ImageFiletype::Pic => "pic",
+            ImageFiletype::Ppm | ImageFiletype::Pgm => "pnm",
+            ImageFiletype::Psd => "psd",
+            ImageFiletype::Hdr => "hdr",
+            ImageFiletype::Qoi => "qoi",
+            ImageFiletype::Svg => "svg",
+            ImageFiletype::Dds => "dds",
+            ImageFiletype::Pkm => "pkm",
+            ImageFiletype::Ktx => "ktx",
+            ImageFiletype::Pvr => "pvr",
+            ImageFiletype::Astc => "astc",
+        }
+    }
+
+    /// Checks if support for this filetype was compiled into raylib.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            ImageFiletype::Png => cfg!(feature = "png"),
+            ImageFiletype::Bmp => cfg!(feature = "bmp"),
+            ImageFiletype::Tga => cfg!(feature = "tga"),
+            ImageFiletype::Jpg => cfg!(feature = "jpg"),
+            ImageFiletype::Gif => cfg!(feature = "gif"),
+            ImageFiletype::Pic => cfg!(feature = "pic"),
+            ImageFiletype::Ppm | ImageFiletype::Pgm => cfg!(feature = "pnm"),
+            ImageFiletype::Psd => cfg!(feature = "psd"),
+            ImageFiletype::Hdr => cfg!(feature = "hdr"),
+            ImageFiletype::Qoi => cfg!(feature = "qoi"),
+            ImageFiletype::Svg => cfg!(feature = "svg"),
+            ImageFiletype::Dds => cfg!(feature = "dds"),
+            ImageFiletype::Pkm => cfg!(feature = "pkm"),
+            ImageFiletype::Ktx => cfg!(feature = "ktx"),
+            ImageFiletype::Pvr => cfg!(feature = "pvr"),
+            ImageFiletype::Astc => cfg!(feature = "astc"),
+        }
+    }
+
+    /// Guesses the filetype from the extension of the given path (case insensitive).
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        let filetype = match extension.as_str() {
+            "png" => ImageFiletype::Png,
+            "bmp" => ImageFiletype::Bmp,
+            "tga" => ImageFiletype::Tga,
+            "jpg" | "jpeg" => ImageFiletype::Jpg,
+            "gif" => ImageFiletype::Gif,
+            "pic" => ImageFiletype::Pic,
+            "ppm" => ImageFiletype::Ppm,
+            "pgm" => ImageFiletype::Pgm,
+            "psd" => ImageFiletype::Psd,
+            "hdr" => ImageFiletype::Hdr,
+            "qoi" => ImageFiletype::Qoi,
+            "svg" => ImageFiletype::Svg,
+            "dds" => ImageFiletype::Dds,
+            "pkm" => ImageFiletype::Pkm,
+            "ktx" => ImageFiletype::Ktx,
+            "pvr" => ImageFiletype::Pvr,
+            "astc" => ImageFiletype::Astc,
+            _ => return None
+        };
+        Some(filetype)
+    }
+
+    /// Returns [`Error::UnsupportedFormat`] if support for this filetype was not compiled into raylib.
+    pub(crate) fn check_supported(&self) -> Result<(), Error> {
+        if self.is_supported() { Ok(()) }
+        else { Err(Error::UnsupportedFormat { feature: self.feature() }) }
+    }
 }
 
 /// # Image loading functions
 /// 
 /// ---
 impl Image {
-    /// Loads an image from a file into CPU memory.
-    /// Returns `Err` if there was an error when reading the file.
-    /// Returns `Ok(None)` if the file was successfully read,
-    /// but support for the given file extension was not compiled into raylib,
-    /// or the input file is in an unknown file format.
-    /// Otherwise, returns the loaded image.
-    pub fn load(rl: &Raylib, filename: impl AsRef<std::path::Path>) -> std::io::Result<Option<Image>> {
-        if !filename.as_ref().exists() { return Err(std::io::ErrorKind::NotFound.into()) }
-
-        let filetype = match filename.as_ref().extension().map(|s| s.to_str()).flatten() {
-            Some("png") => ImageFiletype::Png,
-            Some("bmp") => ImageFiletype::Bmp,
-            Some("tga") => ImageFiletype::Tga,
-            Some("jpg") => ImageFiletype::Jpg,
-            Some("gif") => ImageFiletype::Gif,
-            Some("pic") => ImageFiletype::Pic,
-            Some("ppm") => ImageFiletype::Ppm,
-            Some("pgm") => ImageFiletype::Pgm,
-            Some("psd") => ImageFiletype::Psd,
-            Some("hdr") => ImageFiletype::Hdr,
-            Some("qoi") => ImageFiletype::Qoi,
-            Some("svg") => ImageFiletype::Svg,
-            Some("dds") => ImageFiletype::Dds,
-            Some("pkm") => ImageFiletype::Pkm,
-            Some("ktx") => ImageFiletype::Ktx,
-            Some("pvr") => ImageFiletype::Pvr,
-            Some("astc") => ImageFiletype::Astc,
-            _ => return Ok(None)
-        };
+    /// Loads an image from a file into CPU memory (through the installed [`Vfs`](crate::core::vfs::Vfs), if any).
+    ///
+    /// Returns [`Error::UnknownFormat`] if the file extension isn't a known image format,
+    /// [`Error::UnsupportedFormat`] if support for it was not compiled into raylib,
+    /// and [`Error::Io`] if there was an error when reading the file.
+    pub fn load(rl: &Raylib, filename: impl AsRef<std::path::Path>) -> Result<Image, Error> {
+        let filetype = ImageFiletype::from_path(&filename).ok_or(Error::UnknownFormat)?;
+        filetype.check_supported()?;
 
-        let file = std::fs::read(filename)?;
-        Ok(Image::load_from_memory(rl, filetype, &file))
+        let file = crate::core::vfs::read(filename)?;
+        Image::load_from_memory(rl, filetype, &file)
     }
 
     /// Loads an image from a memory buffer containing raw data.
-    /// Returns `None` if `data`'s size doesn't correspond to the width, height, and format of the image.
+    /// Returns [`Error::SizeMismatch`] if `data`'s size doesn't correspond to the width, height, and format of the image,
+    /// and [`Error::InvalidData`] if width or height are less than 1.
     /// This is a reimplementation of the `LoadImageRaw` raylib function, which doesn't allow loading a raw image from a memory buffer.
     /// It doesn't take a `headerSize` parameter since you can just index the slice to remove any header present.
-    /// # Panics
-    /// Panics if width or height are less than 1.
     /// # Examples
     /// Load a raw image from a file:
     /// ```
@@ -252,14 +307,14 @@ impl Image {
     ///     # break
     /// }
     /// ```
-    pub fn load_raw(_rl: &Raylib, data: &[u8], width: i32, height: i32, format: PixelFormat) -> Option<Image> {
-        assert!(width > 0 && height > 0);
+    pub fn load_raw(_rl: &Raylib, data: &[u8], width: i32, height: i32, format: PixelFormat) -> Result<Image, Error> {
+        if width <= 0 || height <= 0 { return Err(Error::InvalidData) }
 
         let size = unsafe { ffi::GetPixelDataSize(width, height, format as i32) };
-        if data.len() != size as usize { return None }
+        if data.len() != size as usize { return Err(Error::SizeMismatch) }
 
         let data_ptr = unsafe { ffi::MemAlloc(size as u32) };
-        if data_ptr.is_null() { return None }
+        if data_ptr.is_null() { return Err(Error::InvalidData) }
 
         // SAFETY: `data.len()` and `size` are equal
         // data_ptr is not null
@@ -273,52 +328,55 @@ impl Image {
             mipmaps: 1
         };
 
-        Some(Image { image, format, size: size as usize })
+        Ok(Image { image, format, size: size as usize })
     }
 
     /// Loads an image from a memory buffer (in the given filetype's encoding).
-    /// Returns `None` if support for the given format was not compiled into raylib.
+    /// Returns [`Error::UnsupportedFormat`] if support for the given format was not compiled into raylib,
+    /// and [`Error::InvalidData`] if the data couldn't be decoded.
     /// If you need to create an image from raw data (no filetype), use [`Image::load_raw`].
-    pub fn load_from_memory(_rl: &Raylib, filetype: ImageFiletype, data: &[u8]) -> Option<Image> {
+    pub fn load_from_memory(_rl: &Raylib, filetype: ImageFiletype, data: &[u8]) -> Result<Image, Error> {
+        filetype.check_supported()?;
         let filetype = filetype.extension();
 
         let image = unsafe { ffi::LoadImageFromMemory(filetype.as_ptr(), data.as_ptr(), data.len() as i32) };
 
-        Image::from_ffi(image)
+        Image::from_ffi(image).ok_or(Error::InvalidData)
     }
 
     /// Loads animated image data.
-    /// Returns `None` if there was an error when loading the image.
+    /// Returns [`Error::UnsupportedFormat`] if support for the given format was not compiled into raylib,
+    /// and [`Error::InvalidData`] if the data couldn't be decoded.
     /// - The resulting `data` buffer includes all frames next to one another.
     /// - The number of frames is returned.
     /// - All frames are in RGBA format.
     /// - Only the GIF filetype is supported (other filetypes will load a single image).
     /// - Frames delay data is discarded.
-    pub fn load_image_anim_from_memory(_rl: &Raylib, filetype: ImageFiletype, data: &[u8]) -> Option<(usize, Image)> {
+    pub fn load_image_anim_from_memory(_rl: &Raylib, filetype: ImageFiletype, data: &[u8]) -> Result<(usize, Image), Error> {
+        filetype.check_supported()?;
         let filetype = filetype.extension();
         let mut frames = 0;
         let image = unsafe { ffi::LoadImageAnimFromMemory(filetype.as_ptr(), data.as_ptr(), data.len() as i32, &mut frames as *mut i32) };
-        let image = Image::from_ffi(image)?;
+        let image = Image::from_ffi(image).ok_or(Error::InvalidData)?;
 
         let frames = frames as usize;
-        Some((frames, image))
+        Ok((frames, image))
     }
 
     /// Loads image from GPU texture data.
     /// Compressed format are not supported.
-    /// Returns `None` if the texture is in a compressed format or if there was an error when reading the data in the texture.
-    pub fn load_from_texture(_rl: &Raylib, texture: &Texture) -> Option<Image> {
+    /// Returns [`Error::InvalidData`] if the texture is in a compressed format or if there was an error when reading the data in the texture.
+    pub fn load_from_texture(_rl: &Raylib, texture: &Texture) -> Result<Image, Error> {
         let image = unsafe { ffi::LoadImageFromTexture(*texture.get_ffi()) };
-        Image::from_ffi(image)
+        Image::from_ffi(image).ok_or(Error::InvalidData)
     }
 
     /// Loads an image from the current screen buffer (= take a screenshot)
-    /// # Panics
-    /// This function panics if there was an error reading the screen buffer data.
-    pub fn load_from_screen(_rl: &Raylib) -> Image {
+    /// Returns [`Error::InvalidData`] if there was an error reading the screen buffer data.
+    pub fn load_from_screen(_rl: &Raylib) -> Result<Image, Error> {
         let image = unsafe { ffi::LoadImageFromScreen() };
 
-        Image::from_ffi(image).unwrap()
+        Image::from_ffi(image).ok_or(Error::InvalidData)
     }
 }
 
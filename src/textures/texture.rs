@@ -2,7 +2,7 @@ use std::{ffi::c_void, marker::PhantomData};
 
 use ffi::{CubemapLayout, Rectangle, TextureFilter, TextureWrap, NPatchInfo, PixelFormat};
 
-use crate::{ffi, prelude::{Raylib, DrawHandle, get_pixel_data_size, Vector2, Color, vec2}};
+use crate::{ffi, prelude::{Raylib, DrawHandle, get_pixel_data_size, Vector2, Color, vec2}, Error};
 
 use super::image::Image;
 
@@ -157,29 +157,23 @@ impl ffi::RenderTexture {
 /// ---
 impl Texture {
     /// Loads a texture from a file.
-    /// Returns `Err` if there was an error when reading the file.
-    /// Returns `Ok(None)` if the file was successfully read,
-    /// but support for the given file extension was not compiled into raylib,
-    /// or the input file is in an unknown file format.
-    /// Otherwise, returns the loaded texture.
-    pub fn load(rl: &Raylib, filename: impl AsRef<std::path::Path>) -> std::io::Result<Option<Texture>> {
+    /// Fails like [`Image::load`], or with [`Error::GpuUploadFailed`] if the texture couldn't be created.
+    pub fn load(rl: &Raylib, filename: impl AsRef<std::path::Path>) -> Result<Texture, Error> {
         let image = Image::load(rl, filename)?;
-        if let Some(image) = image {
-            Ok(Self::load_from_image(rl, &image))
-        } else { Ok(None) }
+        Self::load_from_image(rl, &image)
     }
 
     /// Load texture from image data.
-    /// Returns `None` if there was an error loading the texture.
+    /// Returns [`Error::GpuUploadFailed`] if there was an error loading the texture.
     #[inline]
-    pub fn load_from_image(_rl: &Raylib, image: &Image) -> Option<Texture> {
+    pub fn load_from_image(_rl: &Raylib, image: &Image) -> Result<Texture, Error> {
         let texture = unsafe { ffi::LoadTextureFromImage(image.get_ffi_image()) };
-        Texture::from_ffi(texture)
+        Texture::from_ffi(texture).ok_or(Error::GpuUploadFailed)
     }
 
     /// Loads an empty texture in the given format.
-    /// Returns `None` if there was an error creating the texture.
-    pub fn load_empty(_rl: &Raylib, width: u32, height: u32, format: PixelFormat) -> Option<Texture> {
+    /// Returns [`Error::GpuUploadFailed`] if there was an error creating the texture.
+    pub fn load_empty(_rl: &Raylib, width: u32, height: u32, format: PixelFormat) -> Result<Texture, Error> {
         let empty_image = ffi::Image {
             data: std::ptr::null_mut(),
             width: width as i32, height: height as i32,
@@ -188,34 +182,34 @@ impl Texture {
         };
 
         let texture = unsafe { ffi::LoadTextureFromImage(empty_image) };
-        Texture::from_ffi(texture)
+        Texture::from_ffi(texture).ok_or(Error::GpuUploadFailed)
     }
 
     /// Load a cubemap texture from an image.
-    /// Returns `None` if there was an error loading the texture.
+    /// Returns [`Error::GpuUploadFailed`] if there was an error loading the texture.
     #[inline]
-    pub fn load_cubmap(_rl: &Raylib, image: &Image, layout: CubemapLayout) -> Option<Texture> {
+    pub fn load_cubmap(_rl: &Raylib, image: &Image, layout: CubemapLayout) -> Result<Texture, Error> {
         let texture = unsafe { ffi::LoadTextureCubemap(image.get_ffi_image(), layout as i32) };
-        Texture::from_ffi(texture)
+        Texture::from_ffi(texture).ok_or(Error::GpuUploadFailed)
     }
 }
 
 impl RenderTexture {
     /// Create a render texture of the given size.
-    /// Returns `None` if there was an error when loading the texture.
+    /// Returns [`Error::GpuUploadFailed`] if there was an error when loading the texture.
     #[inline]
-    pub fn load(_rl: &Raylib, width: u32, height: u32) -> Option<RenderTexture> {
+    pub fn load(_rl: &Raylib, width: u32, height: u32) -> Result<RenderTexture, Error> {
         let texture = unsafe { ffi::LoadRenderTexture(width as i32, height as i32) };
-        RenderTexture::from_ffi(texture)
+        RenderTexture::from_ffi(texture).ok_or(Error::GpuUploadFailed)
     }
 }
 
 impl Texture {
     /// Updates the texture with the given image.
-    /// Returns `Err(())` if the image's format or size does not correspond to the texture.
-    pub fn update(&self, image: &Image) -> Result<(), ()> {
-        if self.0.format != image.format() as i32 { return Err(()) }
-        if self.0.width as u32 != image.width() || self.0.height as u32 != image.height() { return Err(()) }
+    /// Returns [`Error::SizeMismatch`] if the image's format or size does not correspond to the texture.
+    pub fn update(&self, image: &Image) -> Result<(), Error> {
+        if self.0.format != image.format() as i32 { return Err(Error::SizeMismatch) }
+        if self.0.width as u32 != image.width() || self.0.height as u32 != image.height() { return Err(Error::SizeMismatch) }
 
         unsafe { ffi::UpdateTexture(self.0, image.get_ffi_image().data) };
         Ok(())
@@ -223,24 +217,24 @@ impl Texture {
 
     /// Updates the texture with the given raw image data.
     /// The data must be in the same format as the texture.
-    /// Returns `Err(())` if the buffer's size does not correspond to the texture.
-    pub fn update_raw(&self, buffer: &[u8]) -> Result<(), ()> {
-        if get_pixel_data_size(self.0.width, self.0.height, self.0.format.try_into().unwrap()) as usize != buffer.len() { return Err(()) }
+    /// Returns [`Error::SizeMismatch`] if the buffer's size does not correspond to the texture.
+    pub fn update_raw(&self, buffer: &[u8]) -> Result<(), Error> {
+        if get_pixel_data_size(self.0.width, self.0.height, self.0.format.try_into().unwrap()) as usize != buffer.len() { return Err(Error::SizeMismatch) }
 
         unsafe { ffi::UpdateTexture(self.0, buffer.as_ptr() as *const c_void) };
         Ok(())
     }
 
     /// Updates part of a texture with the given image.
-    /// Returns `Err(())` if:
+    /// Returns [`Error::SizeMismatch`] if:
     /// - The image's format does not correspond to the texture
     /// - The image's size does not correspond to `rec`.
     /// - `rec` is out of texture bounds.
-    pub fn update_rec(&self, rec: Rectangle, image: &Image) -> Result<(), ()> {
-        if self.0.format != image.format() as i32 { return Err(()) }
-        if rec.width as u32 != image.width() || rec.height as u32 != image.height() { return Err(()) }
+    pub fn update_rec(&self, rec: Rectangle, image: &Image) -> Result<(), Error> {
+        if self.0.format != image.format() as i32 { return Err(Error::SizeMismatch) }
+        if rec.width as u32 != image.width() || rec.height as u32 != image.height() { return Err(Error::SizeMismatch) }
         if rec.x < 0.0 || rec.y < 0.0 || (rec.x + rec.width) as u32 > self.0.width as u32 || (rec.y + rec.height) as u32 >= self.0.height as u32 {
-            return Err(())
+            return Err(Error::SizeMismatch)
         }
 
         unsafe { ffi::UpdateTextureRec(self.0, rec, image.get_ffi_image().data) };
@@ -249,28 +243,38 @@ impl Texture {
 
     /// Updates part of a texture with the given raw image data.
     /// The data must be in the same format as the texture.
-    /// Returns `Err(())` if:
+    /// Returns [`Error::SizeMismatch`] if:
     /// - The buffer's size does not correspond to `rec`.
     /// - `rec` is out of texture bounds.
-    pub fn update_rec_raw(&self, rec: Rectangle, buffer: &[u8]) -> Result<(), ()> {
-        if get_pixel_data_size(rec.width as i32, rec.height as i32, self.0.format.try_into().unwrap()) as usize != buffer.len() { return Err(()) }
+    pub fn update_rec_raw(&self, rec: Rectangle, buffer: &[u8]) -> Result<(), Error> {
+        if get_pixel_data_size(rec.width as i32, rec.height as i32, self.0.format.try_into().unwrap()) as usize != buffer.len() { return Err(Error::SizeMismatch) }
         if rec.x < 0.0 || rec.y < 0.0 || (rec.x + rec.width) as u32 > self.0.width as u32 || (rec.y + rec.height) as u32 >= self.0.height as u32 {
-            return Err(())
+            return Err(Error::SizeMismatch)
         }
 
         unsafe { ffi::UpdateTextureRec(self.0, rec, buffer.as_ptr() as *const c_void) };
         Ok(())
     }
 
+    /// Replaces this texture with one loaded from `image`, which is unloaded.
+    /// The texture is left unchanged if the new one couldn't be loaded.
+    fn replace_from_image(&mut self, image: ffi::Image) -> Result<(), Error> {
+        let texture = unsafe { ffi::LoadTextureFromImage(image) };
+        unsafe { ffi::UnloadImage(image) };
+
+        // the previous texture is unloaded when dropped
+        *self = Texture::from_ffi(texture).ok_or(Error::GpuUploadFailed)?;
+        Ok(())
+    }
+
     /// Resizes this texture to new dimensions, losing image data in the process. 
+    /// Returns [`Error::GpuUploadFailed`] if the new texture couldn't be loaded, in which case this texture is left unchanged.
     /// # Safety
     /// Since [`Texture`]s are neither `Send` nor `Sync`, if the current texture was well created,
     /// it should be living on the main thread.
     /// This means it does not have to take a [`Raylib`] parameter.
     /// Note that this function is not safe to be called from any context other than the main thread.
-    pub fn resize_lossy(&mut self, new_width: u32, new_height: u32) {
-        unsafe { ffi::UnloadTexture(self.0) };
-
+    pub fn resize_lossy(&mut self, new_width: u32, new_height: u32) -> Result<(), Error> {
         let empty_image = ffi::Image {
             data: std::ptr::null_mut(),
             width: new_width as i32, height: new_height as i32,
@@ -279,52 +283,50 @@ impl Texture {
         };
 
         let texture = unsafe { ffi::LoadTextureFromImage(empty_image) };
-        *self = Texture::from_ffi(texture).unwrap();
+        *self = Texture::from_ffi(texture).ok_or(Error::GpuUploadFailed)?;
+        Ok(())
     }
 
     /// Resizes this texture using the bicubic scaling algorithm. 
     /// See [`Image::resize_bicubic`] for limitations.
+    /// Returns [`Error::GpuUploadFailed`] if the new texture couldn't be loaded, in which case this texture is left unchanged.
     /// # Safety
     /// Since [`Texture`]s are neither `Send` nor `Sync`, if the current texture was well created,
     /// it should be living on the main thread.
     /// This means it does not have to take a [`Raylib`] parameter.
     /// Note that this function is not safe to be called from any context other than the main thread.
-    pub fn resize_bicubic(&mut self, new_width: u32, new_height: u32) {
+    pub fn resize_bicubic(&mut self, new_width: u32, new_height: u32) -> Result<(), Error> {
         let mut image = unsafe { ffi::LoadImageFromTexture(self.0) };
         unsafe { ffi::ImageResize(&mut image as *mut _, new_width as i32, new_height as i32) };
-
-        let texture = unsafe { ffi::LoadTextureFromImage(image) };
-        *self = Texture::from_ffi(texture).unwrap();
+        self.replace_from_image(image)
     }
 
     /// Resizes this texture using the nearest neighbour algorithm. 
     /// See [`Image::resize_nn`] for limitations.
+    /// Returns [`Error::GpuUploadFailed`] if the new texture couldn't be loaded, in which case this texture is left unchanged.
     /// # Safety
     /// Since [`Texture`]s are neither `Send` nor `Sync`, if the current texture was well created,
     /// it should be living on the main thread.
     /// This means it does not have to take a [`Raylib`] parameter.
     /// Note that this function is not safe to be called from any context other than the main thread.
-    pub fn resize_nn(&mut self, new_width: u32, new_height: u32) {
+    pub fn resize_nn(&mut self, new_width: u32, new_height: u32) -> Result<(), Error> {
         let mut image = unsafe { ffi::LoadImageFromTexture(self.0) };
         unsafe { ffi::ImageResizeNN(&mut image as *mut _, new_width as i32, new_height as i32) };
-
-        let texture = unsafe { ffi::LoadTextureFromImage(image) };
-        *self = Texture::from_ffi(texture).unwrap();
+        self.replace_from_image(image)
     }
 
     /// Crops part of the texture and fills out of bounds part with the given color.
     /// See [`Image::resize_canvas`] for limitations.
+    /// Returns [`Error::GpuUploadFailed`] if the new texture couldn't be loaded, in which case this texture is left unchanged.
     /// # Safety
     /// Since [`Texture`]s are neither `Send` nor `Sync`, if the current texture was well created,
     /// it should be living on the main thread.
     /// This means it does not have to take a [`Raylib`] parameter.
     /// Note that this function is not safe to be called from any context other than the main thread.
-    pub fn resize_canvas(&mut self, new_width: u32, new_height: u32, offset_x: i32, offset_y: i32, fill: Color) {
+    pub fn resize_canvas(&mut self, new_width: u32, new_height: u32, offset_x: i32, offset_y: i32, fill: Color) -> Result<(), Error> {
         let mut image = unsafe { ffi::LoadImageFromTexture(self.0) };
         unsafe { ffi::ImageResizeCanvas(&mut image as *mut _, new_width as i32, new_height as i32, offset_x, offset_y, fill) };
-
-        let texture = unsafe { ffi::LoadTextureFromImage(image) };
-        *self = Texture::from_ffi(texture).unwrap();
+        self.replace_from_image(image)
     }
 }
 